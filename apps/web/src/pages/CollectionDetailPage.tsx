@@ -592,7 +592,11 @@ export function CollectionDetailPage() {
         throw new Error(t("app.err_cannot_buy_own_token"));
       }
       const client = getCollectionClient(collection);
-      const payload = client.buildBuyTokenInvoke({ tokenId: token.tokenId });
+      const payload = client.buildBuyTokenInvoke({
+        tokenId: token.tokenId,
+        buyer: connectedAddress,
+        price: sale.price,
+      });
       payload.signers = [
         {
           account: neonWallet.getScriptHashFromAddress(connectedAddress),
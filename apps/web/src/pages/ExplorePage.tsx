@@ -138,7 +138,11 @@ export function ExplorePage() {
         throw new Error(t("app.err_cannot_buy_own_token"));
       }
       const client = getCollectionClient(card.collection);
-      const payload = client.buildBuyTokenInvoke({ tokenId: card.token.tokenId });
+      const payload = client.buildBuyTokenInvoke({
+        tokenId: card.token.tokenId,
+        buyer: connectedAddress,
+        price: card.sale.price,
+      });
       payload.signers = [
         {
           account: neonWallet.getScriptHashFromAddress(connectedAddress),
@@ -1,7 +1,42 @@
 pub const KEY_TOTAL_SUPPLY: &[u8] = b"mnr:total";
+pub const KEY_SCHEMA_VERSION: &[u8] = b"mnr:schema:version";
+pub const KEY_MIGRATION_CURSOR: &[u8] = b"mnr:schema:migration_cursor";
 pub const KEY_COLLECTION_COUNTER: &[u8] = b"mnr:collection:counter";
 pub const KEY_GLOBAL_TOKEN_COUNTER: &[u8] = b"mnr:token:global_counter";
+pub const KEY_PLATFORM_ADMIN: &[u8] = b"mnr:platform:admin";
+pub const KEY_PLATFORM_PAUSED: &[u8] = b"mnr:platform:paused";
+pub const KEY_MAX_COLLECTIONS_PER_OWNER: &[u8] = b"mnr:platform:max_collections_per_owner";
 pub const TOKEN_SERIAL_FACTOR: i64 = 1_000_000;
+pub const MAX_COLLECTIONS_PER_OWNER_LIMIT: i64 = 1000;
+pub const MAX_PAGE_SIZE: i64 = 100;
+// Each minted token writes roughly fifteen storage entries plus a Transfer event; 50 keeps a batch well inside
+// the default invocation GAS limit.
+pub const MAX_BATCH_MINT_SIZE: usize = 50;
+// A backfill entry costs one permission read and at most two index writes, so it can run ten times a mint batch.
+pub const MAX_OPERATOR_BACKFILL_SIZE: usize = 500;
+
+// CryptoLib native contract hash in little-endian byte order.
+pub const CRYPTO_LIB_HASH: [u8; 20] = [
+    0x1b, 0xf5, 0x75, 0xab, 0x11, 0x89, 0x68, 0x84, 0x13, 0x61, 0x0a, 0x35, 0xa1, 0x28, 0x86, 0xcd, 0xe0, 0xb6,
+    0x6c, 0x72,
+];
+
+// StdLib native contract hash in little-endian byte order.
+pub const STD_LIB_HASH: [u8; 20] = [
+    0xc0, 0xef, 0x39, 0xce, 0xe0, 0xe4, 0xe9, 0x25, 0xc6, 0xc2, 0xa0, 0x6a, 0x79, 0xe1, 0x44, 0x0d, 0xd8, 0x6f,
+    0xce, 0xac,
+];
+
+// GAS native contract hash in little-endian byte order.
+pub const GAS_TOKEN_HASH: [u8; 20] = [
+    0xcf, 0x76, 0xe2, 0x8b, 0xd0, 0x06, 0x2c, 0x4a, 0x47, 0x8e, 0xe3, 0x55, 0x61, 0x01, 0x13, 0x19, 0xf3, 0xcf,
+    0xa4, 0xd2,
+];
+
+pub const SCHEMA_VERSION_LEGACY_REFS: i64 = 1;
+pub const SCHEMA_VERSION_STRING_FIELDS: i64 = 2;
+pub const SCHEMA_VERSION_OWNER_TOKEN_INDEX: i64 = 3;
+pub const SCHEMA_VERSION_CURRENT: i64 = 3;
 
 pub const FIELD_OWNER: u8 = 0x01;
 pub const FIELD_NAME_REF: u8 = 0x02;
@@ -14,6 +49,15 @@ pub const FIELD_ROYALTY_BPS: u8 = 0x08;
 pub const FIELD_TRANSFERABLE: u8 = 0x09;
 pub const FIELD_PAUSED: u8 = 0x0A;
 pub const FIELD_CREATED_AT: u8 = 0x0B;
+pub const FIELD_PENDING_OWNER: u8 = 0x0C;
+pub const FIELD_PENDING_CLEAR_OPERATORS: u8 = 0x0D;
+pub const FIELD_OPERATOR_EPOCH: u8 = 0x0E;
+pub const FIELD_OWNER_INDEX: u8 = 0x0F;
+pub const FIELD_EXTRA_DATA: u8 = 0x10;
+
+pub const FIELD_METADATA_FROZEN: u8 = 0x51;
+pub const FIELD_RENAME_LOCK_AT: u8 = 0x52;
+pub const FIELD_ARCHIVED: u8 = 0x53;
 
 pub const TOKEN_FIELD_COLLECTION_ID: u8 = 0x11;
 pub const TOKEN_FIELD_OWNER: u8 = 0x12;
@@ -22,6 +66,14 @@ pub const TOKEN_FIELD_PROPERTIES_REF: u8 = 0x14;
 pub const TOKEN_FIELD_BURNED: u8 = 0x15;
 pub const TOKEN_FIELD_MINTED_AT: u8 = 0x16;
 pub const TOKEN_FIELD_CLASS: u8 = 0x17;
+pub const TOKEN_FIELD_METADATA_FROZEN: u8 = 0x18;
+pub const TOKEN_FIELD_ROYALTY_OVERRIDE: u8 = 0x19;
+pub const TOKEN_FIELD_ROYALTY_BPS: u8 = 0x1A;
+pub const TOKEN_FIELD_ROYALTY_RECIPIENT: u8 = 0x1B;
+pub const TOKEN_FIELD_REVEAL_PENDING: u8 = 0x1C;
+pub const TOKEN_FIELD_METADATA_VERSION: u8 = 0x1D;
+pub const TOKEN_FIELD_APPROVED: u8 = 0x1E;
+pub const TOKEN_FIELD_OWNER_INDEX: u8 = 0x1F;
 
 pub const TOKEN_CLASS_STANDARD: i64 = 0;
 pub const TOKEN_CLASS_MEMBERSHIP: i64 = 1;
@@ -42,5 +94,55 @@ pub const CHECKIN_FIELD_INTERVAL_SECONDS: u8 = 0x36;
 pub const CHECKIN_FIELD_MAX_PER_WALLET: u8 = 0x37;
 pub const CHECKIN_FIELD_MINT_PROOF_NFT: u8 = 0x38;
 
+pub const ROYALTY_SPLIT_FIELD_ACCOUNT: u8 = 0x61;
+pub const ROYALTY_SPLIT_FIELD_BPS: u8 = 0x62;
+pub const MAX_ROYALTY_RECIPIENTS: i64 = 10;
+
+pub const REVEAL_FIELD_ENABLED: u8 = 0x71;
+pub const REVEAL_FIELD_PLACEHOLDER_URI: u8 = 0x72;
+pub const REVEAL_FIELD_PROVENANCE_HASH: u8 = 0x73;
+pub const REVEAL_FIELD_REVEALED: u8 = 0x74;
+pub const REVEAL_FIELD_BASE_URI: u8 = 0x75;
+pub const REVEAL_FIELD_SEED: u8 = 0x76;
+pub const REVEAL_FIELD_OFFSET: u8 = 0x77;
+pub const REVEAL_FIELD_SUPPLY: u8 = 0x78;
+pub const REVEAL_FIELD_REVEALED_AT: u8 = 0x79;
+pub const REVEAL_FIELD_ENTROPY: u8 = 0x7A;
+
+pub const LISTING_FIELD_SELLER: u8 = 0x81;
+pub const LISTING_FIELD_PRICE: u8 = 0x82;
+pub const LISTING_FIELD_LISTED_AT: u8 = 0x83;
+
+pub const OFFER_KIND_TOKEN: u8 = 0x01;
+pub const OFFER_KIND_COLLECTION: u8 = 0x02;
+pub const OFFER_FIELD_AMOUNT: u8 = 0x91;
+pub const OFFER_FIELD_EXPIRES_AT: u8 = 0x92;
+pub const OFFER_FIELD_CREATED_AT: u8 = 0x93;
+
+pub const AUCTION_FIELD_SELLER: u8 = 0xA1;
+pub const AUCTION_FIELD_RESERVE: u8 = 0xA2;
+pub const AUCTION_FIELD_END_AT: u8 = 0xA3;
+pub const AUCTION_FIELD_HIGHEST_BIDDER: u8 = 0xA4;
+pub const AUCTION_FIELD_HIGHEST_BID: u8 = 0xA5;
+pub const AUCTION_FIELD_STARTED_AT: u8 = 0xA6;
+pub const AUCTION_EXTENSION_WINDOW_MS: i64 = 300_000;
+pub const MAX_AUCTION_DURATION_MS: i64 = 30 * 24 * 60 * 60 * 1000;
+pub const AUCTION_SETTLE_GRACE_MS: i64 = 7 * 24 * 60 * 60 * 1000;
+
+pub const MARKET_OP_BUY: i64 = 1;
+pub const MARKET_OP_TOKEN_OFFER: i64 = 2;
+pub const MARKET_OP_COLLECTION_BID: i64 = 3;
+pub const MARKET_OP_AUCTION_BID: i64 = 4;
+
+pub const OPERATOR_PERMISSION_LEGACY: i64 = 0x01;
+pub const OPERATOR_PERMISSION_MINT: i64 = 0x02;
+pub const OPERATOR_PERMISSION_BURN: i64 = 0x04;
+pub const OPERATOR_PERMISSION_UPDATE_METADATA: i64 = 0x08;
+pub const OPERATOR_PERMISSION_CONFIGURE_DROP: i64 = 0x10;
+pub const OPERATOR_PERMISSION_MANAGE_WHITELIST: i64 = 0x20;
+pub const OPERATOR_PERMISSION_CONFIGURE_CHECKIN: i64 = 0x40;
+pub const OPERATOR_PERMISSION_SET_ROYALTY: i64 = 0x80;
+pub const OPERATOR_PERMISSION_ALL: i64 = 0xFE;
+
 pub const CHECKIN_WALLET_FIELD_COUNT: u8 = 0x41;
 pub const CHECKIN_WALLET_FIELD_LAST_AT: u8 = 0x42;
@@ -59,19 +59,42 @@ pub fn canonical_account_id(storage: &NeoStorageContext, account_ref: i64) -> i6
 
     match neo_devpack::abi::resolve_value(account_ref) {
         Some(NeoValue::ByteString(account_bytes)) if account_bytes.len() == 20 => {
-            let account_id = fold_bytes_to_i64(account_bytes.as_slice());
-            if account_id <= 0 {
-                return 0;
-            }
-
-            let key = account_hash_key(account_id);
-            let _ = write_bytes(storage, &key, &account_bytes);
-            account_id
+            account_id_from_hash160(storage, &account_bytes)
         }
         _ => account_ref,
     }
 }
 
+pub fn account_id_from_hash160(storage: &NeoStorageContext, account_bytes: &NeoByteString) -> i64 {
+    let account_id = fold_bytes_to_i64(account_bytes.as_slice());
+    if account_id <= 0 {
+        return 0;
+    }
+
+    let key = account_hash_key(account_id);
+    let _ = write_bytes(storage, &key, account_bytes);
+    account_id
+}
+
+// Runtime.Transaction is exposed as the transaction array; the sender sits at index 3.
+pub fn transaction_sender_id(storage: &NeoStorageContext) -> i64 {
+    match NeoRuntime::get_script_container() {
+        Ok(NeoValue::Array(fields)) => match fields.get(3) {
+            Some(NeoValue::ByteString(sender)) if sender.len() == 20 => account_id_from_hash160(storage, sender),
+            _ => 0,
+        },
+        _ => 0,
+    }
+}
+
+// Mirrors the C# AssertDirectInvocation guard: only the entry script may call through.
+pub fn is_direct_invocation() -> bool {
+    match (NeoRuntime::get_calling_script_hash(), NeoRuntime::get_entry_script_hash()) {
+        (Ok(calling), Ok(entry)) => calling.as_slice() == entry.as_slice(),
+        _ => false,
+    }
+}
+
 pub fn account_hash160(storage: &NeoStorageContext, account_id: i64) -> NeoByteString {
     if account_id <= 0 {
         return NeoByteString::from_slice(&[0u8; 20]);
@@ -94,6 +117,23 @@ pub fn hash160_value_from_account_id(storage: &NeoStorageContext, account_id: Op
     }
 }
 
+pub fn optional_account_id(storage: &NeoStorageContext, account_ref: i64) -> Option<i64> {
+    if account_ref <= 0 {
+        return None;
+    }
+
+    if let Some(NeoValue::Null) = neo_devpack::abi::resolve_value(account_ref) {
+        return None;
+    }
+
+    let account_id = canonical_account_id(storage, account_ref);
+    if account_id > 0 {
+        Some(account_id)
+    } else {
+        None
+    }
+}
+
 pub fn hash160_ref_from_account_id(storage: &NeoStorageContext, account_id: i64) -> i64 {
     if account_id <= 0 {
         return 0;
@@ -106,13 +146,34 @@ pub fn write_string_field(storage: &NeoStorageContext, key: &[u8], value: &NeoSt
     write_bytes(storage, key, &bytes)
 }
 
+pub fn schema_version(storage: &NeoStorageContext) -> i64 {
+    let stored = read_i64(storage, KEY_SCHEMA_VERSION);
+    if stored > 0 {
+        stored
+    } else {
+        SCHEMA_VERSION_LEGACY_REFS
+    }
+}
+
+pub fn is_legacy_string_ref(bytes: &[u8]) -> bool {
+    // Integer refs were persisted as 8-byte little-endian values, so their high bytes are zero.
+    bytes.len() == 8 && (core::str::from_utf8(bytes).is_err() || bytes.contains(&0))
+}
+
 pub fn read_string_field(storage: &NeoStorageContext, key: &[u8]) -> NeoString {
+    let migrated = schema_version(storage) >= SCHEMA_VERSION_STRING_FIELDS;
     if let Some(bytes) = read_bytes(storage, key) {
-        if let Ok(text) = core::str::from_utf8(bytes.as_slice()) {
-            return NeoString::from_str(text);
+        if migrated || !is_legacy_string_ref(bytes.as_slice()) {
+            if let Ok(text) = core::str::from_utf8(bytes.as_slice()) {
+                return NeoString::from_str(text);
+            }
         }
     }
 
+    if migrated {
+        return NeoString::from_str("");
+    }
+
     // Backward compatibility for older persisted integer-ref layouts.
     string_ref(read_i64(storage, key))
 }
@@ -147,6 +208,19 @@ pub fn check_witness_for_account_ref(storage: &NeoStorageContext, account_ref: i
     false
 }
 
+pub fn platform_admin(storage: &NeoStorageContext) -> i64 {
+    read_i64(storage, KEY_PLATFORM_ADMIN)
+}
+
+pub fn check_platform_admin_witness(storage: &NeoStorageContext) -> bool {
+    let admin = platform_admin(storage);
+    admin > 0 && check_witness_for_account_ref(storage, admin)
+}
+
+pub fn is_platform_paused(storage: &NeoStorageContext) -> bool {
+    read_bool(storage, KEY_PLATFORM_PAUSED)
+}
+
 pub fn token_id_value(token_id: i64) -> NeoValue {
     NeoValue::ByteString(neo_devpack::abi::bytes_from_i64(token_id))
 }
@@ -218,11 +292,17 @@ pub fn emit_collection_upserted(storage: &NeoStorageContext, collection_id: i64)
     let _ = NeoRuntime::notify(&label, &state);
 }
 
-pub fn emit_collection_operator_updated(storage: &NeoStorageContext, collection_id: i64, operator_id: i64, enabled: bool) {
+pub fn emit_collection_operator_updated(
+    storage: &NeoStorageContext,
+    collection_id: i64,
+    operator_id: i64,
+    permissions: i64,
+) {
     let mut state = NeoArray::new();
     state.push(token_id_value(collection_id));
     state.push(hash160_value_from_account_id(storage, Some(operator_id)));
-    state.push(NeoValue::Boolean(NeoBoolean::new(enabled)));
+    state.push(NeoValue::Boolean(NeoBoolean::new(permissions != 0)));
+    state.push(NeoValue::Integer(NeoInteger::new(permissions)));
 
     let label = NeoString::from_str("CollectionOperatorUpdated");
     let _ = NeoRuntime::notify(&label, &state);
@@ -231,7 +311,11 @@ pub fn emit_collection_operator_updated(storage: &NeoStorageContext, collection_
 pub fn emit_token_upserted(storage: &NeoStorageContext, token_id: i64) {
     let collection_id = read_i64(storage, &token_field_key(token_id, TOKEN_FIELD_COLLECTION_ID));
     let owner_id = read_i64(storage, &token_field_key(token_id, TOKEN_FIELD_OWNER));
-    let uri = read_string_field(storage, &token_field_key(token_id, TOKEN_FIELD_URI_REF));
+    let uri = resolve_token_uri(
+        storage,
+        token_id,
+        read_string_field(storage, &token_field_key(token_id, TOKEN_FIELD_URI_REF)),
+    );
     let properties = read_string_field(storage, &token_field_key(token_id, TOKEN_FIELD_PROPERTIES_REF));
     let burned = read_bool(storage, &token_field_key(token_id, TOKEN_FIELD_BURNED));
     let minted_at = read_i64(storage, &token_field_key(token_id, TOKEN_FIELD_MINTED_AT));
@@ -257,6 +341,261 @@ pub fn token_exists(storage: &NeoStorageContext, token_id: i64) -> bool {
     read_i64(storage, &token_field_key(token_id, TOKEN_FIELD_COLLECTION_ID)) > 0
 }
 
+pub fn max_collections_per_owner(storage: &NeoStorageContext) -> i64 {
+    let configured = read_i64(storage, KEY_MAX_COLLECTIONS_PER_OWNER);
+    if configured > 1 {
+        configured
+    } else {
+        1
+    }
+}
+
+pub fn owner_collection_count(storage: &NeoStorageContext, owner: i64) -> i64 {
+    read_i64(storage, &owner_collection_count_key(owner))
+}
+
+pub fn add_owner_collection_index(storage: &NeoStorageContext, owner: i64, collection_id: i64) -> bool {
+    let next = owner_collection_count(storage, owner) + 1;
+    write_i64(storage, &owner_collections_key(owner, next), collection_id)
+        && write_i64(storage, &collection_field_key(collection_id, FIELD_OWNER_INDEX), next)
+        && write_i64(storage, &owner_collection_count_key(owner), next)
+}
+
+pub fn remove_owner_collection_index(storage: &NeoStorageContext, owner: i64, collection_id: i64) -> bool {
+    let position = read_i64(storage, &collection_field_key(collection_id, FIELD_OWNER_INDEX));
+    let count = owner_collection_count(storage, owner);
+    if position <= 0 || position > count {
+        return true;
+    }
+
+    if position != count {
+        let last = read_i64(storage, &owner_collections_key(owner, count));
+        if !write_i64(storage, &owner_collections_key(owner, position), last)
+            || !write_i64(storage, &collection_field_key(last, FIELD_OWNER_INDEX), position)
+        {
+            return false;
+        }
+    }
+
+    write_i64(storage, &owner_collections_key(owner, count), 0)
+        && write_i64(storage, &owner_collection_count_key(owner), count - 1)
+        && write_i64(storage, &collection_field_key(collection_id, FIELD_OWNER_INDEX), 0)
+}
+
+pub fn ensure_owner_collection_index(storage: &NeoStorageContext, owner: i64) -> bool {
+    // Collections created before the owner index existed are only reachable through the dedicated slot.
+    if owner_collection_count(storage, owner) > 0 {
+        return true;
+    }
+
+    let legacy_collection_id = read_i64(storage, &owner_collection_key(owner));
+    if legacy_collection_id <= 0 {
+        return true;
+    }
+
+    add_owner_collection_index(storage, owner, legacy_collection_id)
+}
+
+// The dedicated slot moves to one of the owner's remaining live collections and is only cleared once none are left.
+pub fn repoint_owner_collection_slot(storage: &NeoStorageContext, owner: i64, leaving_collection_id: i64) -> bool {
+    if read_i64(storage, &owner_collection_key(owner)) != leaving_collection_id {
+        return true;
+    }
+
+    let count = owner_collection_count(storage, owner);
+    let mut replacement = 0;
+    let mut index = 1;
+    while index <= count {
+        let candidate = read_i64(storage, &owner_collections_key(owner, index));
+        if candidate > 0 && candidate != leaving_collection_id && !is_collection_archived(storage, candidate) {
+            replacement = candidate;
+            break;
+        }
+        index += 1;
+    }
+
+    write_i64(storage, &owner_collection_key(owner), replacement)
+}
+
+pub fn owner_archived_collection_count(storage: &NeoStorageContext, owner: i64) -> i64 {
+    read_i64(storage, &owner_archived_collection_count_key(owner))
+}
+
+pub fn owner_has_collection_capacity(storage: &NeoStorageContext, owner: i64) -> bool {
+    let max_per_owner = max_collections_per_owner(storage);
+    if max_per_owner <= 1 {
+        return read_i64(storage, &owner_collection_key(owner)) <= 0;
+    }
+
+    // Archived collections stay indexed under their owner but no longer count against the limit.
+    ensure_owner_collection_index(storage, owner)
+        && owner_collection_count(storage, owner) - owner_archived_collection_count(storage, owner) < max_per_owner
+}
+
+pub fn is_collection_archived(storage: &NeoStorageContext, collection_id: i64) -> bool {
+    read_bool(storage, &collection_field_key(collection_id, FIELD_ARCHIVED))
+}
+
+pub fn is_collection_active(storage: &NeoStorageContext, collection_id: i64) -> bool {
+    !is_platform_paused(storage)
+        && !read_bool(storage, &collection_field_key(collection_id, FIELD_PAUSED))
+        && !is_collection_archived(storage, collection_id)
+}
+
+pub fn is_collection_metadata_frozen(storage: &NeoStorageContext, collection_id: i64) -> bool {
+    read_bool(storage, &collection_field_key(collection_id, FIELD_METADATA_FROZEN))
+}
+
+pub fn is_token_metadata_frozen(storage: &NeoStorageContext, token_id: i64) -> bool {
+    if read_bool(storage, &token_field_key(token_id, TOKEN_FIELD_METADATA_FROZEN)) {
+        return true;
+    }
+
+    let collection_id = read_i64(storage, &token_field_key(token_id, TOKEN_FIELD_COLLECTION_ID));
+    is_collection_metadata_frozen(storage, collection_id)
+}
+
+pub fn royalty_split_count(storage: &NeoStorageContext, collection_id: i64) -> i64 {
+    read_i64(storage, &royalty_split_count_key(collection_id))
+}
+
+// Resolves (account id, bps) pairs for a token: a token override wins, then the collection split table,
+// and otherwise the collection owner takes the full rate.
+pub fn royalty_recipients(storage: &NeoStorageContext, token_id: i64) -> Vec<(i64, i64)> {
+    let mut recipients = Vec::new();
+    if read_bool(storage, &token_field_key(token_id, TOKEN_FIELD_ROYALTY_OVERRIDE)) {
+        let recipient = read_i64(storage, &token_field_key(token_id, TOKEN_FIELD_ROYALTY_RECIPIENT));
+        let bps = read_i64(storage, &token_field_key(token_id, TOKEN_FIELD_ROYALTY_BPS));
+        if recipient > 0 && bps > 0 {
+            recipients.push((recipient, bps));
+        }
+        return recipients;
+    }
+
+    let collection_id = read_i64(storage, &token_field_key(token_id, TOKEN_FIELD_COLLECTION_ID));
+    if collection_id <= 0 {
+        return recipients;
+    }
+
+    let royalty_bps = read_i64(storage, &collection_field_key(collection_id, FIELD_ROYALTY_BPS));
+    if royalty_bps <= 0 {
+        return recipients;
+    }
+
+    let split_count = royalty_split_count(storage, collection_id);
+    if split_count <= 0 {
+        recipients.push((read_i64(storage, &collection_field_key(collection_id, FIELD_OWNER)), royalty_bps));
+        return recipients;
+    }
+
+    let mut index = 1;
+    while index <= split_count {
+        let account_id = read_i64(storage, &royalty_split_key(collection_id, index, ROYALTY_SPLIT_FIELD_ACCOUNT));
+        let bps = read_i64(storage, &royalty_split_key(collection_id, index, ROYALTY_SPLIT_FIELD_BPS));
+        if account_id > 0 && bps > 0 {
+            recipients.push((account_id, bps));
+        }
+        index += 1;
+    }
+
+    recipients
+}
+
+// Amounts are floored per recipient, so the payouts never add up to more than the sale price.
+pub fn royalty_payouts(storage: &NeoStorageContext, token_id: i64, sale_price: i64) -> Option<Vec<(i64, i64)>> {
+    let mut payouts = Vec::new();
+    for (account_id, bps) in royalty_recipients(storage, token_id) {
+        let amount = sale_price.checked_mul(bps)? / 10000;
+        if amount > 0 {
+            payouts.push((account_id, amount));
+        }
+    }
+
+    Some(payouts)
+}
+
+pub fn sha256(data: &[u8]) -> Option<NeoByteString> {
+    let mut args = NeoArray::new();
+    args.push(NeoValue::ByteString(NeoByteString::from_slice(data)));
+
+    match NeoContractRuntime::call(
+        &NeoByteString::from_slice(&CRYPTO_LIB_HASH),
+        &NeoString::from_str("sha256"),
+        &args,
+    ) {
+        Ok(NeoValue::ByteString(digest)) if digest.len() == 32 => Some(digest),
+        _ => None,
+    }
+}
+
+pub fn std_serialize(value: NeoValue) -> Option<NeoByteString> {
+    let mut args = NeoArray::new();
+    args.push(value);
+
+    match NeoContractRuntime::call(
+        &NeoByteString::from_slice(&STD_LIB_HASH),
+        &NeoString::from_str("serialize"),
+        &args,
+    ) {
+        Ok(NeoValue::ByteString(bytes)) => Some(bytes),
+        _ => None,
+    }
+}
+
+pub fn std_deserialize(bytes: &NeoByteString) -> Option<NeoValue> {
+    let mut args = NeoArray::new();
+    args.push(NeoValue::ByteString(bytes.clone()));
+
+    NeoContractRuntime::call(
+        &NeoByteString::from_slice(&STD_LIB_HASH),
+        &NeoString::from_str("deserialize"),
+        &args,
+    )
+    .ok()
+}
+
+// Runtime.GetRandom yields an unsigned integer wider than i64, so it is kept whole as its serialized bytes.
+pub fn runtime_entropy() -> Option<NeoByteString> {
+    let random = NeoRuntime::get_random().ok()?;
+    std_serialize(NeoValue::Integer(random))
+}
+
+// The provenance commitment is sha256(baseUri || seed), published before the first mint.
+pub fn reveal_commitment(base_uri: &NeoString, seed: &[u8]) -> Option<NeoByteString> {
+    let mut preimage = base_uri.as_str().as_bytes().to_vec();
+    preimage.extend_from_slice(seed);
+    sha256(&preimage)
+}
+
+pub fn is_reveal_pending(storage: &NeoStorageContext, collection_id: i64) -> bool {
+    read_bool(storage, &reveal_config_key(collection_id, REVEAL_FIELD_ENABLED))
+        && !read_bool(storage, &reveal_config_key(collection_id, REVEAL_FIELD_REVEALED))
+}
+
+// Hidden tokens keep the placeholder in storage; once revealed their URI is derived from the shifted serial.
+pub fn resolve_token_uri(storage: &NeoStorageContext, token_id: i64, stored_uri: NeoString) -> NeoString {
+    if !read_bool(storage, &token_field_key(token_id, TOKEN_FIELD_REVEAL_PENDING)) {
+        return stored_uri;
+    }
+
+    let collection_id = read_i64(storage, &token_field_key(token_id, TOKEN_FIELD_COLLECTION_ID));
+    if !read_bool(storage, &reveal_config_key(collection_id, REVEAL_FIELD_REVEALED)) {
+        return stored_uri;
+    }
+
+    let serial = token_id - collection_id * TOKEN_SERIAL_FACTOR;
+    let supply = read_i64(storage, &reveal_config_key(collection_id, REVEAL_FIELD_SUPPLY));
+    let offset = read_i64(storage, &reveal_config_key(collection_id, REVEAL_FIELD_OFFSET));
+    let revealed_serial = if supply > 0 && serial >= 1 && serial <= supply {
+        (serial - 1 + offset) % supply + 1
+    } else {
+        serial
+    };
+
+    let base_uri = read_string_field(storage, &reveal_config_key(collection_id, REVEAL_FIELD_BASE_URI));
+    NeoString::from_str(&format!("{}{}", base_uri.as_str(), revealed_serial))
+}
+
 pub fn load_balance(storage: &NeoStorageContext, owner: i64) -> i64 {
     read_i64(storage, &balance_key(owner))
 }
@@ -293,13 +632,171 @@ pub fn is_membership_soulbound(storage: &NeoStorageContext, collection_id: i64)
     )
 }
 
-pub fn can_manage_collection(storage: &NeoStorageContext, collection_id: i64, actor: i64) -> bool {
+pub fn is_token_in_auction(storage: &NeoStorageContext, token_id: i64) -> bool {
+    read_i64(storage, &auction_key(token_id, AUCTION_FIELD_SELLER)) > 0
+}
+
+// Auctioned tokens stay locked until settleAuction or cancelAuction releases them.
+pub fn is_token_tradable(storage: &NeoStorageContext, token_id: i64) -> bool {
+    !is_token_in_auction(storage, token_id) && is_token_transfer_allowed(storage, token_id)
+}
+
+// Pause, transferability and soulbound rules only; the auction lock is left to is_token_tradable.
+pub fn is_token_transfer_allowed(storage: &NeoStorageContext, token_id: i64) -> bool {
+    let collection_id = read_i64(storage, &token_field_key(token_id, TOKEN_FIELD_COLLECTION_ID));
+    if is_platform_paused(storage) || read_bool(storage, &collection_field_key(collection_id, FIELD_PAUSED)) {
+        return false;
+    }
+
+    if !read_bool(storage, &collection_field_key(collection_id, FIELD_TRANSFERABLE)) {
+        return false;
+    }
+
+    let token_class = read_i64(storage, &token_field_key(token_id, TOKEN_FIELD_CLASS));
+    !(token_class == TOKEN_CLASS_MEMBERSHIP && is_membership_soulbound(storage, collection_id))
+}
+
+pub fn operator_permissions(storage: &NeoStorageContext, collection_id: i64, account_id: i64) -> i64 {
+    let stored = read_i64(storage, &operator_key(collection_id, account_id));
+    if stored <= 0 {
+        return 0;
+    }
+
+    // Grants from an older operator epoch were revoked wholesale by an ownership transfer.
+    if read_i64(storage, &operator_epoch_key(collection_id, account_id))
+        != read_i64(storage, &collection_field_key(collection_id, FIELD_OPERATOR_EPOCH))
+    {
+        return 0;
+    }
+
+    // Boolean grants persisted before permission masks carry the legacy mint/burn rights.
+    if stored & OPERATOR_PERMISSION_LEGACY != 0 {
+        return (stored & OPERATOR_PERMISSION_ALL) | OPERATOR_PERMISSION_MINT | OPERATOR_PERMISSION_BURN;
+    }
+
+    stored & OPERATOR_PERMISSION_ALL
+}
+
+// A stored position only counts while the slot it names still holds the operator; epoch resets empty the index.
+fn collection_operator_position(storage: &NeoStorageContext, collection_id: i64, operator_id: i64) -> i64 {
+    let position = read_i64(storage, &operator_position_key(collection_id, operator_id));
+    if position <= 0
+        || position > read_i64(storage, &operator_count_key(collection_id))
+        || read_i64(storage, &operator_index_key(collection_id, position)) != operator_id
+    {
+        return 0;
+    }
+
+    position
+}
+
+pub fn index_collection_operator(storage: &NeoStorageContext, collection_id: i64, operator_id: i64) -> bool {
+    if collection_operator_position(storage, collection_id, operator_id) > 0 {
+        return true;
+    }
+
+    let next = read_i64(storage, &operator_count_key(collection_id)) + 1;
+    write_i64(storage, &operator_index_key(collection_id, next), operator_id)
+        && write_i64(storage, &operator_position_key(collection_id, operator_id), next)
+        && write_i64(storage, &operator_count_key(collection_id), next)
+}
+
+pub fn unindex_collection_operator(storage: &NeoStorageContext, collection_id: i64, operator_id: i64) -> bool {
+    let position = collection_operator_position(storage, collection_id, operator_id);
+    if position <= 0 {
+        return true;
+    }
+
+    let count = read_i64(storage, &operator_count_key(collection_id));
+    if position != count {
+        let last = read_i64(storage, &operator_index_key(collection_id, count));
+        if !write_i64(storage, &operator_index_key(collection_id, position), last)
+            || !write_i64(storage, &operator_position_key(collection_id, last), position)
+        {
+            return false;
+        }
+    }
+
+    write_i64(storage, &operator_index_key(collection_id, count), 0)
+        && write_i64(storage, &operator_count_key(collection_id), count - 1)
+        && write_i64(storage, &operator_position_key(collection_id, operator_id), 0)
+}
+
+pub fn can_manage_collection(storage: &NeoStorageContext, collection_id: i64, actor: i64, permission: i64) -> bool {
     let owner = read_i64(storage, &collection_field_key(collection_id, FIELD_OWNER));
     if owner == actor {
         return true;
     }
 
-    read_bool(storage, &operator_key(collection_id, actor))
+    operator_permissions(storage, collection_id, actor) & permission == permission
+}
+
+pub fn owner_token_count(storage: &NeoStorageContext, owner: i64) -> i64 {
+    read_i64(storage, &owner_token_count_key(owner))
+}
+
+// Once the schema-3 migration has run the find keys are the only owner index; the positional list is frozen.
+pub fn add_owner_token_index(storage: &NeoStorageContext, owner: i64, token_id: i64) -> bool {
+    if !write_bytes(
+        storage,
+        &find_owner_token_key(owner, token_id),
+        &neo_devpack::abi::bytes_from_i64(token_id),
+    ) {
+        return false;
+    }
+
+    if is_owner_token_index_ready(storage) {
+        return true;
+    }
+
+    let next = owner_token_count(storage, owner) + 1;
+    write_i64(storage, &owner_token_key(owner, next), token_id)
+        && write_i64(storage, &token_field_key(token_id, TOKEN_FIELD_OWNER_INDEX), next)
+        && write_i64(storage, &owner_token_count_key(owner), next)
+}
+
+// Collection entries keep burned tokens, matching getCollectionTokens; the global entry only tracks live tokens.
+pub fn add_token_find_entries(storage: &NeoStorageContext, token_id: i64) -> bool {
+    let collection_id = read_i64(storage, &token_field_key(token_id, TOKEN_FIELD_COLLECTION_ID));
+    let serial = token_id - collection_id * TOKEN_SERIAL_FACTOR;
+    let token_bytes = neo_devpack::abi::bytes_from_i64(token_id);
+    if !write_bytes(storage, &find_collection_token_key(collection_id, serial), &token_bytes) {
+        return false;
+    }
+
+    read_bool(storage, &token_field_key(token_id, TOKEN_FIELD_BURNED))
+        || write_bytes(storage, &find_token_key(token_id), &token_bytes)
+}
+
+// Tokens minted before the index existed have no position yet; migration picks them up.
+pub fn remove_owner_token_index(storage: &NeoStorageContext, owner: i64, token_id: i64) -> bool {
+    if is_owner_token_index_ready(storage) {
+        return delete_key(storage, &find_owner_token_key(owner, token_id));
+    }
+
+    let position = read_i64(storage, &token_field_key(token_id, TOKEN_FIELD_OWNER_INDEX));
+    let count = owner_token_count(storage, owner);
+    if position <= 0 || position > count {
+        return true;
+    }
+
+    if position != count {
+        let last = read_i64(storage, &owner_token_key(owner, count));
+        if !write_i64(storage, &owner_token_key(owner, position), last)
+            || !write_i64(storage, &token_field_key(last, TOKEN_FIELD_OWNER_INDEX), position)
+        {
+            return false;
+        }
+    }
+
+    write_i64(storage, &owner_token_key(owner, count), 0)
+        && write_i64(storage, &owner_token_count_key(owner), count - 1)
+        && write_i64(storage, &token_field_key(token_id, TOKEN_FIELD_OWNER_INDEX), 0)
+        && delete_key(storage, &find_owner_token_key(owner, token_id))
+}
+
+pub fn is_owner_token_index_ready(storage: &NeoStorageContext) -> bool {
+    schema_version(storage) >= SCHEMA_VERSION_OWNER_TOKEN_INDEX
 }
 
 pub fn collect_active_tokens(
@@ -343,6 +840,89 @@ pub fn collect_active_tokens(
     items
 }
 
+// Pre-index fallback for cursor pages: live tokens with ids above `after`, in ascending id order.
+pub fn collect_active_token_page(
+    storage: &NeoStorageContext,
+    owner_filter: Option<i64>,
+    after: i64,
+    limit: i64,
+) -> NeoArray<NeoValue> {
+    let global_total = read_i64(storage, KEY_GLOBAL_TOKEN_COUNTER);
+    let mut token_ids = Vec::new();
+
+    let mut cursor = 1;
+    while cursor <= global_total {
+        let token_id = read_i64(storage, &global_token_key(cursor));
+        cursor += 1;
+
+        if token_id <= after || !token_exists(storage, token_id) {
+            continue;
+        }
+
+        if read_bool(storage, &token_field_key(token_id, TOKEN_FIELD_BURNED)) {
+            continue;
+        }
+
+        if let Some(owner) = owner_filter {
+            if read_i64(storage, &token_field_key(token_id, TOKEN_FIELD_OWNER)) != owner {
+                continue;
+            }
+        }
+
+        token_ids.push(token_id);
+    }
+
+    token_ids.sort_unstable();
+    let mut items = NeoArray::new();
+    for token_id in token_ids.into_iter().take(limit as usize) {
+        items.push(token_id_value(token_id));
+    }
+    items
+}
+
+pub fn collect_collections(
+    storage: &NeoStorageContext,
+    offset: i64,
+    limit: i64,
+    paused_filter: Option<bool>,
+    owner_filter: Option<i64>,
+) -> NeoArray<NeoValue> {
+    let mut items = NeoArray::new();
+    let collection_total = read_i64(storage, KEY_COLLECTION_COUNTER);
+
+    let mut skipped = 0;
+    let mut collection_id = 1;
+    while collection_id <= collection_total && (items.len() as i64) < limit {
+        let current = collection_id;
+        collection_id += 1;
+
+        if !collection_exists(storage, current) {
+            continue;
+        }
+
+        if let Some(paused) = paused_filter {
+            if read_bool(storage, &collection_field_key(current, FIELD_PAUSED)) != paused {
+                continue;
+            }
+        }
+
+        if let Some(owner) = owner_filter {
+            if read_i64(storage, &collection_field_key(current, FIELD_OWNER)) != owner {
+                continue;
+            }
+        }
+
+        if skipped < offset {
+            skipped += 1;
+            continue;
+        }
+
+        items.push(NeoValue::Array(collection_to_array(storage, current)));
+    }
+
+    items
+}
+
 pub fn string_ref(ref_id: i64) -> NeoString {
     neo_devpack::abi::string_from_i64(ref_id)
 }
@@ -394,6 +974,7 @@ pub fn collection_to_array(storage: &NeoStorageContext, collection_id: i64) -> N
         storage,
         &collection_field_key(collection_id, FIELD_CREATED_AT),
     ))));
+    result.push(NeoValue::Boolean(NeoBoolean::new(is_collection_archived(storage, collection_id))));
     result
 }
 
@@ -408,9 +989,10 @@ pub fn token_to_array(storage: &NeoStorageContext, token_id: i64) -> NeoArray<Ne
         storage,
         &token_field_key(token_id, TOKEN_FIELD_OWNER),
     ))));
-    result.push(NeoValue::String(read_string_field(
+    result.push(NeoValue::String(resolve_token_uri(
         storage,
-        &token_field_key(token_id, TOKEN_FIELD_URI_REF),
+        token_id,
+        read_string_field(storage, &token_field_key(token_id, TOKEN_FIELD_URI_REF)),
     )));
     result.push(NeoValue::String(read_string_field(
         storage,
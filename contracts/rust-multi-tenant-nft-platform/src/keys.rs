@@ -46,6 +46,36 @@ pub fn operator_key(collection_id: i64, operator: i64) -> Vec<u8> {
     key
 }
 
+pub fn operator_epoch_key(collection_id: i64, operator: i64) -> Vec<u8> {
+    let mut key = b"mnr:operator_epoch:".to_vec();
+    key.extend_from_slice(&collection_id.to_le_bytes());
+    key.push(b':');
+    key.extend_from_slice(&operator.to_le_bytes());
+    key
+}
+
+pub fn operator_index_key(collection_id: i64, index: i64) -> Vec<u8> {
+    let mut key = b"mnr:operator_index:".to_vec();
+    key.extend_from_slice(&collection_id.to_le_bytes());
+    key.push(b':');
+    key.extend_from_slice(&index.to_le_bytes());
+    key
+}
+
+pub fn operator_position_key(collection_id: i64, operator: i64) -> Vec<u8> {
+    let mut key = b"mnr:operator_position:".to_vec();
+    key.extend_from_slice(&collection_id.to_le_bytes());
+    key.push(b':');
+    key.extend_from_slice(&operator.to_le_bytes());
+    key
+}
+
+pub fn operator_count_key(collection_id: i64) -> Vec<u8> {
+    let mut key = b"mnr:operator_count:".to_vec();
+    key.extend_from_slice(&collection_id.to_le_bytes());
+    key
+}
+
 pub fn account_hash_key(account_id: i64) -> Vec<u8> {
     let mut key = b"mnr:account:".to_vec();
     key.extend_from_slice(&account_id.to_le_bytes());
@@ -58,6 +88,40 @@ pub fn owner_collection_key(owner: i64) -> Vec<u8> {
     key
 }
 
+pub fn owner_collections_key(owner: i64, index: i64) -> Vec<u8> {
+    let mut key = b"mnr:owner:collections:".to_vec();
+    key.extend_from_slice(&owner.to_le_bytes());
+    key.push(b':');
+    key.extend_from_slice(&index.to_le_bytes());
+    key
+}
+
+pub fn owner_collection_count_key(owner: i64) -> Vec<u8> {
+    let mut key = b"mnr:owner:collection_count:".to_vec();
+    key.extend_from_slice(&owner.to_le_bytes());
+    key
+}
+
+pub fn owner_token_key(owner: i64, index: i64) -> Vec<u8> {
+    let mut key = b"mnr:owner:token:".to_vec();
+    key.extend_from_slice(&owner.to_le_bytes());
+    key.push(b':');
+    key.extend_from_slice(&index.to_le_bytes());
+    key
+}
+
+pub fn owner_token_count_key(owner: i64) -> Vec<u8> {
+    let mut key = b"mnr:owner:token_count:".to_vec();
+    key.extend_from_slice(&owner.to_le_bytes());
+    key
+}
+
+pub fn owner_archived_collection_count_key(owner: i64) -> Vec<u8> {
+    let mut key = b"mnr:owner:archived_collection_count:".to_vec();
+    key.extend_from_slice(&owner.to_le_bytes());
+    key
+}
+
 pub fn drop_config_key(collection_id: i64, field: u8) -> Vec<u8> {
     let mut key = b"mnr:drop:cfg:".to_vec();
     key.extend_from_slice(&collection_id.to_le_bytes());
@@ -104,3 +168,116 @@ pub fn membership_balance_key(collection_id: i64, account: i64) -> Vec<u8> {
     key.extend_from_slice(&account.to_le_bytes());
     key
 }
+
+pub fn royalty_split_key(collection_id: i64, index: i64, field: u8) -> Vec<u8> {
+    let mut key = b"mnr:royalty:split:".to_vec();
+    key.extend_from_slice(&collection_id.to_le_bytes());
+    key.push(b':');
+    key.extend_from_slice(&index.to_le_bytes());
+    key.push(field);
+    key
+}
+
+pub fn royalty_split_count_key(collection_id: i64) -> Vec<u8> {
+    let mut key = b"mnr:royalty:split_count:".to_vec();
+    key.extend_from_slice(&collection_id.to_le_bytes());
+    key
+}
+
+pub fn reveal_config_key(collection_id: i64, field: u8) -> Vec<u8> {
+    let mut key = b"mnr:reveal:cfg:".to_vec();
+    key.extend_from_slice(&collection_id.to_le_bytes());
+    key.push(field);
+    key
+}
+
+pub fn approval_for_all_key(owner: i64, operator: i64) -> Vec<u8> {
+    let mut key = b"mnr:approval:all:".to_vec();
+    key.extend_from_slice(&owner.to_le_bytes());
+    key.push(b':');
+    key.extend_from_slice(&operator.to_le_bytes());
+    key
+}
+
+pub fn token_listing_key(token_id: i64, field: u8) -> Vec<u8> {
+    let mut key = b"mnr:market:listing:".to_vec();
+    key.extend_from_slice(&token_id.to_le_bytes());
+    key.push(field);
+    key
+}
+
+pub fn auction_key(token_id: i64, field: u8) -> Vec<u8> {
+    let mut key = b"mnr:market:auction:".to_vec();
+    key.extend_from_slice(&token_id.to_le_bytes());
+    key.push(field);
+    key
+}
+
+// GAS the contract owes an account from sales, royalties and refunds, paid out through withdraw.
+pub fn market_balance_key(account: i64) -> Vec<u8> {
+    let mut key = b"mnr:market:balance:".to_vec();
+    key.extend_from_slice(&account.to_le_bytes());
+    key
+}
+
+// Token offers and collection bids share one layout, keyed by the token or collection id they target.
+pub fn market_offer_key(kind: u8, target: i64, bidder: i64, field: u8) -> Vec<u8> {
+    let mut key = b"mnr:market:offer:".to_vec();
+    key.push(kind);
+    key.extend_from_slice(&target.to_le_bytes());
+    key.push(b':');
+    key.extend_from_slice(&bidder.to_le_bytes());
+    key.push(field);
+    key
+}
+
+// Iterator keys use big-endian suffixes so Storage.Find yields them in numeric order.
+pub fn find_token_prefix() -> Vec<u8> {
+    b"mnr:find:token:".to_vec()
+}
+
+pub fn find_token_key(token_id: i64) -> Vec<u8> {
+    let mut key = find_token_prefix();
+    key.extend_from_slice(&token_id.to_be_bytes());
+    key
+}
+
+pub fn find_owner_token_prefix(owner: i64) -> Vec<u8> {
+    let mut key = b"mnr:find:owner:".to_vec();
+    key.extend_from_slice(&owner.to_le_bytes());
+    key.push(b':');
+    key
+}
+
+pub fn find_owner_token_key(owner: i64, token_id: i64) -> Vec<u8> {
+    let mut key = find_owner_token_prefix(owner);
+    key.extend_from_slice(&token_id.to_be_bytes());
+    key
+}
+
+pub fn find_collection_token_prefix(collection_id: i64) -> Vec<u8> {
+    let mut key = b"mnr:find:collection:".to_vec();
+    key.extend_from_slice(&collection_id.to_le_bytes());
+    key.push(b':');
+    key
+}
+
+pub fn find_collection_token_key(collection_id: i64, serial: i64) -> Vec<u8> {
+    let mut key = find_collection_token_prefix(collection_id);
+    key.extend_from_slice(&serial.to_be_bytes());
+    key
+}
+
+pub fn find_market_offer_prefix(kind: u8, target: i64) -> Vec<u8> {
+    let mut key = b"mnr:find:offer:".to_vec();
+    key.push(kind);
+    key.extend_from_slice(&target.to_le_bytes());
+    key.push(b':');
+    key
+}
+
+pub fn find_market_offer_key(kind: u8, target: i64, bidder: i64) -> Vec<u8> {
+    let mut key = find_market_offer_prefix(kind, target);
+    key.extend_from_slice(&bidder.to_be_bytes());
+    key
+}
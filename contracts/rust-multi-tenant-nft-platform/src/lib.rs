@@ -56,7 +56,8 @@ neo_manifest_overlay!(
         "parameters": [
           { "name": "collectionId", "type": "ByteArray" },
           { "name": "operator", "type": "Hash160" },
-          { "name": "enabled", "type": "Boolean" }
+          { "name": "enabled", "type": "Boolean" },
+          { "name": "permissions", "type": "Integer" }
         ]
       },
       {
@@ -110,6 +111,225 @@ neo_manifest_overlay!(
           { "name": "checkedAt", "type": "Integer" },
           { "name": "proofTokenId", "type": "ByteArray" }
         ]
+      },
+      {
+        "name": "SchemaMigrationProgress",
+        "parameters": [
+          { "name": "fromVersion", "type": "Integer" },
+          { "name": "toVersion", "type": "Integer" },
+          { "name": "processed", "type": "Integer" },
+          { "name": "total", "type": "Integer" },
+          { "name": "completed", "type": "Boolean" }
+        ]
+      },
+      {
+        "name": "PlatformPaused",
+        "parameters": [
+          { "name": "paused", "type": "Boolean" },
+          { "name": "admin", "type": "Hash160" },
+          { "name": "updatedAt", "type": "Integer" }
+        ]
+      },
+      {
+        "name": "CollectionOwnerProposed",
+        "parameters": [
+          { "name": "collectionId", "type": "ByteArray" },
+          { "name": "owner", "type": "Hash160" },
+          { "name": "pendingOwner", "type": "Hash160" },
+          { "name": "clearOperators", "type": "Boolean" }
+        ]
+      },
+      {
+        "name": "CollectionOwnerChanged",
+        "parameters": [
+          { "name": "collectionId", "type": "ByteArray" },
+          { "name": "previousOwner", "type": "Hash160" },
+          { "name": "newOwner", "type": "Hash160" },
+          { "name": "operatorsCleared", "type": "Boolean" }
+        ]
+      },
+      {
+        "name": "MaxCollectionsPerOwnerUpdated",
+        "parameters": [
+          { "name": "maxPerOwner", "type": "Integer" }
+        ]
+      },
+      {
+        "name": "DedicatedExtraDataUpdated",
+        "parameters": [
+          { "name": "collectionId", "type": "ByteArray" },
+          { "name": "size", "type": "Integer" }
+        ]
+      },
+      {
+        "name": "CollectionMetadataFrozen",
+        "parameters": [
+          { "name": "collectionId", "type": "ByteArray" },
+          { "name": "frozenAt", "type": "Integer" }
+        ]
+      },
+      {
+        "name": "TokenMetadataFrozen",
+        "parameters": [
+          { "name": "tokenId", "type": "ByteArray" },
+          { "name": "collectionId", "type": "ByteArray" },
+          { "name": "frozenAt", "type": "Integer" }
+        ]
+      },
+      {
+        "name": "CollectionRenameLockUpdated",
+        "parameters": [
+          { "name": "collectionId", "type": "ByteArray" },
+          { "name": "lockAt", "type": "Integer" }
+        ]
+      },
+      {
+        "name": "CollectionArchived",
+        "parameters": [
+          { "name": "collectionId", "type": "ByteArray" },
+          { "name": "owner", "type": "Hash160" },
+          { "name": "ownerSlotReleased", "type": "Boolean" },
+          { "name": "archivedAt", "type": "Integer" }
+        ]
+      },
+      {
+        "name": "RoyaltySplitsUpdated",
+        "parameters": [
+          { "name": "collectionId", "type": "ByteArray" },
+          { "name": "recipientCount", "type": "Integer" },
+          { "name": "totalBps", "type": "Integer" }
+        ]
+      },
+      {
+        "name": "TokenRoyaltyUpdated",
+        "parameters": [
+          { "name": "tokenId", "type": "ByteArray" },
+          { "name": "recipient", "type": "Hash160" },
+          { "name": "royaltyBps", "type": "Integer" },
+          { "name": "overridden", "type": "Boolean" }
+        ]
+      },
+      {
+        "name": "RevealConfigured",
+        "parameters": [
+          { "name": "collectionId", "type": "ByteArray" },
+          { "name": "provenanceHash", "type": "ByteArray" }
+        ]
+      },
+      {
+        "name": "CollectionRevealed",
+        "parameters": [
+          { "name": "collectionId", "type": "ByteArray" },
+          { "name": "baseUri", "type": "String" },
+          { "name": "offset", "type": "Integer" },
+          { "name": "revealedAt", "type": "Integer" }
+        ]
+      },
+      {
+        "name": "Approval",
+        "parameters": [
+          { "name": "owner", "type": "Hash160" },
+          { "name": "approved", "type": "Hash160" },
+          { "name": "tokenId", "type": "ByteArray" }
+        ]
+      },
+      {
+        "name": "ApprovalForAll",
+        "parameters": [
+          { "name": "owner", "type": "Hash160" },
+          { "name": "operator", "type": "Hash160" },
+          { "name": "approved", "type": "Boolean" }
+        ]
+      },
+      {
+        "name": "TokenListingUpdated",
+        "parameters": [
+          { "name": "tokenId", "type": "ByteArray" },
+          { "name": "seller", "type": "Hash160" },
+          { "name": "price", "type": "Integer" },
+          { "name": "active", "type": "Boolean" },
+          { "name": "listedAt", "type": "Integer" }
+        ]
+      },
+      {
+        "name": "TokenSaleMatched",
+        "parameters": [
+          { "name": "tokenId", "type": "ByteArray" },
+          { "name": "seller", "type": "Hash160" },
+          { "name": "buyer", "type": "Hash160" },
+          { "name": "price", "type": "Integer" },
+          { "name": "at", "type": "Integer" }
+        ]
+      },
+      {
+        "name": "TokenOfferUpdated",
+        "parameters": [
+          { "name": "tokenId", "type": "ByteArray" },
+          { "name": "bidder", "type": "Hash160" },
+          { "name": "amount", "type": "Integer" },
+          { "name": "active", "type": "Boolean" },
+          { "name": "expiresAt", "type": "Integer" }
+        ]
+      },
+      {
+        "name": "CollectionBidUpdated",
+        "parameters": [
+          { "name": "collectionId", "type": "ByteArray" },
+          { "name": "bidder", "type": "Hash160" },
+          { "name": "amount", "type": "Integer" },
+          { "name": "active", "type": "Boolean" },
+          { "name": "expiresAt", "type": "Integer" }
+        ]
+      },
+      {
+        "name": "AuctionCreated",
+        "parameters": [
+          { "name": "tokenId", "type": "ByteArray" },
+          { "name": "seller", "type": "Hash160" },
+          { "name": "reserve", "type": "Integer" },
+          { "name": "endAt", "type": "Integer" }
+        ]
+      },
+      {
+        "name": "AuctionBid",
+        "parameters": [
+          { "name": "tokenId", "type": "ByteArray" },
+          { "name": "bidder", "type": "Hash160" },
+          { "name": "amount", "type": "Integer" },
+          { "name": "endAt", "type": "Integer" }
+        ]
+      },
+      {
+        "name": "AuctionClosed",
+        "parameters": [
+          { "name": "tokenId", "type": "ByteArray" },
+          { "name": "seller", "type": "Hash160" },
+          { "name": "winner", "type": "Hash160" },
+          { "name": "amount", "type": "Integer" },
+          { "name": "closedAt", "type": "Integer" }
+        ]
+      },
+      {
+        "name": "PlatformAdminChanged",
+        "parameters": [
+          { "name": "previousAdmin", "type": "Hash160" },
+          { "name": "newAdmin", "type": "Hash160" }
+        ]
+      },
+      {
+        "name": "MarketBalanceCredited",
+        "parameters": [
+          { "name": "account", "type": "Hash160" },
+          { "name": "amount", "type": "Integer" },
+          { "name": "balance", "type": "Integer" }
+        ]
+      },
+      {
+        "name": "MarketBalanceWithdrawn",
+        "parameters": [
+          { "name": "account", "type": "Hash160" },
+          { "name": "amount", "type": "Integer" }
+        ]
       }
     ]
   }
@@ -0,0 +1,339 @@
+use neo_devpack::prelude::*;
+
+use crate::constants::*;
+use crate::helpers::*;
+use crate::keys::*;
+use crate::methods::market::{clear_token_listing, credit_balance, sale_credits, settle_sale};
+use crate::storage_helpers::*;
+use crate::MultiTenantNftPlatformRust;
+
+fn emit_auction_created(storage: &NeoStorageContext, token_id: i64, seller: i64, reserve: i64, end_at: i64) {
+    let mut state = NeoArray::new();
+    state.push(token_id_value(token_id));
+    state.push(hash160_value_from_account_id(storage, Some(seller)));
+    state.push(NeoValue::Integer(NeoInteger::new(reserve)));
+    state.push(NeoValue::Integer(NeoInteger::new(end_at)));
+
+    let label = NeoString::from_str("AuctionCreated");
+    let _ = NeoRuntime::notify(&label, &state);
+}
+
+fn emit_auction_bid(storage: &NeoStorageContext, token_id: i64, bidder: i64, amount: i64, end_at: i64) {
+    let mut state = NeoArray::new();
+    state.push(token_id_value(token_id));
+    state.push(hash160_value_from_account_id(storage, Some(bidder)));
+    state.push(NeoValue::Integer(NeoInteger::new(amount)));
+    state.push(NeoValue::Integer(NeoInteger::new(end_at)));
+
+    let label = NeoString::from_str("AuctionBid");
+    let _ = NeoRuntime::notify(&label, &state);
+}
+
+fn emit_auction_closed(storage: &NeoStorageContext, token_id: i64, seller: i64, winner: i64, amount: i64) {
+    let mut state = NeoArray::new();
+    state.push(token_id_value(token_id));
+    state.push(hash160_value_from_account_id(storage, Some(seller)));
+    state.push(hash160_value_from_account_id(storage, Some(winner)));
+    state.push(NeoValue::Integer(NeoInteger::new(amount)));
+    state.push(NeoValue::Integer(NeoInteger::new(now())));
+
+    let label = NeoString::from_str("AuctionClosed");
+    let _ = NeoRuntime::notify(&label, &state);
+}
+
+fn clear_auction(storage: &NeoStorageContext, token_id: i64) -> bool {
+    write_i64(storage, &auction_key(token_id, AUCTION_FIELD_SELLER), 0)
+        && write_i64(storage, &auction_key(token_id, AUCTION_FIELD_RESERVE), 0)
+        && write_i64(storage, &auction_key(token_id, AUCTION_FIELD_END_AT), 0)
+        && write_i64(storage, &auction_key(token_id, AUCTION_FIELD_HIGHEST_BIDDER), 0)
+        && write_i64(storage, &auction_key(token_id, AUCTION_FIELD_HIGHEST_BID), 0)
+        && write_i64(storage, &auction_key(token_id, AUCTION_FIELD_STARTED_AT), 0)
+}
+
+fn is_valid_bid(amount: i64, reserve: i64, previous_bid: i64) -> bool {
+    amount > 0 && amount >= reserve && amount > previous_bid
+}
+
+// A bid inside the closing window pushes the end out so there is always time to answer it.
+fn extended_end_at(end_at: i64, current_time: i64) -> i64 {
+    if end_at - current_time < AUCTION_EXTENSION_WINDOW_MS {
+        current_time + AUCTION_EXTENSION_WINDOW_MS
+    } else {
+        end_at
+    }
+}
+
+// Past the grace period an unsettled auction is presumed stuck, so the seller or bidder may unwind it.
+fn is_abandonable(end_at: i64, current_time: i64) -> bool {
+    current_time >= end_at.saturating_add(AUCTION_SETTLE_GRACE_MS)
+}
+
+// The winning escrow returns to the bidder's balance and the token stays with the seller.
+fn close_without_sale(storage: &NeoStorageContext, token_id: i64, seller: i64, bidder: i64, amount: i64) -> bool {
+    if !clear_auction(storage, token_id) {
+        return false;
+    }
+
+    if bidder > 0 && amount > 0 && !credit_balance(storage, bidder, amount) {
+        return false;
+    }
+
+    emit_auction_closed(storage, token_id, seller, 0, 0);
+    true
+}
+
+// Called from onNEP17Payment; the outbid escrow is credited to the previous bidder, who withdraws it.
+pub(crate) fn place_auction_bid(storage: &NeoStorageContext, bidder: i64, token_id: i64, amount: i64) -> bool {
+    let seller = read_i64(storage, &auction_key(token_id, AUCTION_FIELD_SELLER));
+    if seller <= 0 || bidder <= 0 || bidder == seller || amount <= 0 {
+        return false;
+    }
+
+    // A bid on a token that could not change hands at settlement would only end in a refund.
+    if !is_token_transfer_allowed(storage, token_id) {
+        return false;
+    }
+
+    let current_time = now();
+    let end_at = read_i64(storage, &auction_key(token_id, AUCTION_FIELD_END_AT));
+    if current_time >= end_at {
+        return false;
+    }
+
+    let reserve = read_i64(storage, &auction_key(token_id, AUCTION_FIELD_RESERVE));
+    let previous_bidder = read_i64(storage, &auction_key(token_id, AUCTION_FIELD_HIGHEST_BIDDER));
+    let previous_bid = read_i64(storage, &auction_key(token_id, AUCTION_FIELD_HIGHEST_BID));
+    if !is_valid_bid(amount, reserve, previous_bid) {
+        return false;
+    }
+
+    let next_end_at = extended_end_at(end_at, current_time);
+
+    if !write_i64(storage, &auction_key(token_id, AUCTION_FIELD_HIGHEST_BIDDER), bidder)
+        || !write_i64(storage, &auction_key(token_id, AUCTION_FIELD_HIGHEST_BID), amount)
+        || !write_i64(storage, &auction_key(token_id, AUCTION_FIELD_END_AT), next_end_at)
+    {
+        return false;
+    }
+
+    if previous_bidder > 0 && previous_bid > 0 && !credit_balance(storage, previous_bidder, previous_bid) {
+        return false;
+    }
+
+    emit_auction_bid(storage, token_id, bidder, amount, next_end_at);
+    true
+}
+
+#[neo_contract]
+impl MultiTenantNftPlatformRust {
+    #[neo_method(
+        name = "createAuction",
+        param_types = ["Hash160", "ByteArray", "Integer", "Integer"]
+    )]
+    pub fn create_auction(seller: i64, token_id: i64, reserve: i64, end_at: i64) -> bool {
+        if seller <= 0 || token_id <= 0 || reserve <= 0 {
+            return false;
+        }
+
+        let current_time = now();
+        if end_at <= current_time || end_at - current_time > MAX_AUCTION_DURATION_MS {
+            return false;
+        }
+
+        let Some(storage) = storage_context() else {
+            return false;
+        };
+
+        let seller_id = canonical_account_id(&storage, seller);
+        if seller_id <= 0 || !check_witness_for_account_ref(&storage, seller) {
+            return false;
+        }
+
+        if !token_exists(&storage, token_id) || read_bool(&storage, &token_field_key(token_id, TOKEN_FIELD_BURNED)) {
+            return false;
+        }
+
+        if read_i64(&storage, &token_field_key(token_id, TOKEN_FIELD_OWNER)) != seller_id
+            || !is_token_tradable(&storage, token_id)
+        {
+            return false;
+        }
+
+        // A fixed-price listing would let the token sell twice, so the auction replaces it.
+        if !clear_token_listing(&storage, token_id)
+            || !write_i64(&storage, &auction_key(token_id, AUCTION_FIELD_SELLER), seller_id)
+            || !write_i64(&storage, &auction_key(token_id, AUCTION_FIELD_RESERVE), reserve)
+            || !write_i64(&storage, &auction_key(token_id, AUCTION_FIELD_END_AT), end_at)
+            || !write_i64(&storage, &auction_key(token_id, AUCTION_FIELD_HIGHEST_BIDDER), 0)
+            || !write_i64(&storage, &auction_key(token_id, AUCTION_FIELD_HIGHEST_BID), 0)
+            || !write_i64(&storage, &auction_key(token_id, AUCTION_FIELD_STARTED_AT), current_time)
+        {
+            return false;
+        }
+
+        emit_auction_created(&storage, token_id, seller_id, reserve, end_at);
+        true
+    }
+
+    #[neo_method(name = "cancelAuction", param_types = ["Hash160", "ByteArray"])]
+    pub fn cancel_auction(seller: i64, token_id: i64) -> bool {
+        if seller <= 0 || token_id <= 0 {
+            return false;
+        }
+
+        let Some(storage) = storage_context() else {
+            return false;
+        };
+
+        let seller_id = canonical_account_id(&storage, seller);
+        if seller_id <= 0 || !check_witness_for_account_ref(&storage, seller) {
+            return false;
+        }
+
+        // Once someone has bid, the auction can only end through settleAuction.
+        if read_i64(&storage, &auction_key(token_id, AUCTION_FIELD_SELLER)) != seller_id
+            || read_i64(&storage, &auction_key(token_id, AUCTION_FIELD_HIGHEST_BIDDER)) > 0
+        {
+            return false;
+        }
+
+        close_without_sale(&storage, token_id, seller_id, 0, 0)
+    }
+
+    // Anyone may settle once the auction has ended; the outcome no longer depends on the caller.
+    #[neo_method(name = "settleAuction", param_types = ["ByteArray"])]
+    pub fn settle_auction(token_id: i64) -> bool {
+        let Some(storage) = storage_context() else {
+            return false;
+        };
+
+        let seller = read_i64(&storage, &auction_key(token_id, AUCTION_FIELD_SELLER));
+        if seller <= 0 || now() < read_i64(&storage, &auction_key(token_id, AUCTION_FIELD_END_AT)) {
+            return false;
+        }
+
+        let winner = read_i64(&storage, &auction_key(token_id, AUCTION_FIELD_HIGHEST_BIDDER));
+        let amount = read_i64(&storage, &auction_key(token_id, AUCTION_FIELD_HIGHEST_BID));
+        if winner <= 0 {
+            return close_without_sale(&storage, token_id, seller, 0, 0);
+        }
+
+        // The lock is lifted first so the winning transfer can go through transfer_token.
+        if !clear_auction(&storage, token_id) {
+            return false;
+        }
+
+        // A sale that would be refused up front (paused or non-transferable collection, bad payouts) refunds the
+        // winner instead of stranding the escrow; a failure once the token has moved must abort the whole call.
+        if sale_credits(&storage, token_id, seller, winner, amount).is_none() {
+            if !credit_balance(&storage, winner, amount) {
+                return false;
+            }
+
+            emit_auction_closed(&storage, token_id, seller, 0, 0);
+            return true;
+        }
+
+        if !settle_sale(&storage, token_id, seller, winner, amount) {
+            panic!("Failed to settle auction");
+        }
+
+        emit_auction_closed(&storage, token_id, seller, winner, amount);
+        true
+    }
+
+    // A receiver that panics in onNEP11Payment reverts settleAuction outright, so after the grace period the seller
+    // or the highest bidder can close the auction with a refund instead.
+    #[neo_method(name = "abandonAuction", param_types = ["Hash160", "ByteArray"])]
+    pub fn abandon_auction(caller: i64, token_id: i64) -> bool {
+        if caller <= 0 || token_id <= 0 {
+            return false;
+        }
+
+        let Some(storage) = storage_context() else {
+            return false;
+        };
+
+        let caller_id = canonical_account_id(&storage, caller);
+        if caller_id <= 0 || !check_witness_for_account_ref(&storage, caller) {
+            return false;
+        }
+
+        let seller = read_i64(&storage, &auction_key(token_id, AUCTION_FIELD_SELLER));
+        let bidder = read_i64(&storage, &auction_key(token_id, AUCTION_FIELD_HIGHEST_BIDDER));
+        if seller <= 0 || (caller_id != seller && caller_id != bidder) {
+            return false;
+        }
+
+        let end_at = read_i64(&storage, &auction_key(token_id, AUCTION_FIELD_END_AT));
+        if !is_abandonable(end_at, now()) {
+            return false;
+        }
+
+        let amount = read_i64(&storage, &auction_key(token_id, AUCTION_FIELD_HIGHEST_BID));
+        close_without_sale(&storage, token_id, seller, bidder, amount)
+    }
+
+    #[neo_method(name = "getAuction", safe, param_types = ["ByteArray"], return_type = "Array")]
+    pub fn get_auction(token_id: i64) -> i64 {
+        let Some(storage) = storage_context() else {
+            return 0;
+        };
+
+        let seller = read_i64(&storage, &auction_key(token_id, AUCTION_FIELD_SELLER));
+        let highest_bidder = read_i64(&storage, &auction_key(token_id, AUCTION_FIELD_HIGHEST_BIDDER));
+
+        let mut result = NeoArray::new();
+        result.push(NeoValue::Boolean(NeoBoolean::new(seller > 0)));
+        result.push(hash160_value_from_account_id(&storage, Some(seller)));
+        result.push(NeoValue::Integer(NeoInteger::new(read_i64(
+            &storage,
+            &auction_key(token_id, AUCTION_FIELD_RESERVE),
+        ))));
+        result.push(NeoValue::Integer(NeoInteger::new(read_i64(
+            &storage,
+            &auction_key(token_id, AUCTION_FIELD_END_AT),
+        ))));
+        result.push(hash160_value_from_account_id(&storage, Some(highest_bidder)));
+        result.push(NeoValue::Integer(NeoInteger::new(read_i64(
+            &storage,
+            &auction_key(token_id, AUCTION_FIELD_HIGHEST_BID),
+        ))));
+        result.push(NeoValue::Integer(NeoInteger::new(read_i64(
+            &storage,
+            &auction_key(token_id, AUCTION_FIELD_STARTED_AT),
+        ))));
+        to_iterator_handle(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{extended_end_at, is_abandonable, is_valid_bid};
+    use crate::constants::{AUCTION_EXTENSION_WINDOW_MS, AUCTION_SETTLE_GRACE_MS};
+
+    #[test]
+    fn bids_must_meet_the_reserve_and_beat_the_leader() {
+        assert!(!is_valid_bid(90, 100, 0));
+        assert!(is_valid_bid(100, 100, 0));
+        assert!(!is_valid_bid(150, 100, 150));
+        assert!(is_valid_bid(151, 100, 150));
+    }
+
+    #[test]
+    fn late_bids_extend_the_auction() {
+        let end_at = 1_000_000;
+        assert_eq!(extended_end_at(end_at, end_at - AUCTION_EXTENSION_WINDOW_MS - 1), end_at);
+
+        let late = end_at - 1_000;
+        assert_eq!(extended_end_at(end_at, late), late + AUCTION_EXTENSION_WINDOW_MS);
+    }
+
+    #[test]
+    fn abandon_opens_only_after_the_grace_period() {
+        let end_at = 1_000_000;
+        assert!(!is_abandonable(end_at, end_at));
+        assert!(!is_abandonable(end_at, end_at + AUCTION_SETTLE_GRACE_MS - 1));
+        assert!(is_abandonable(end_at, end_at + AUCTION_SETTLE_GRACE_MS));
+    }
+}
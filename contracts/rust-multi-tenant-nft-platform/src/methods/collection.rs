@@ -6,6 +6,94 @@ use crate::keys::*;
 use crate::storage_helpers::*;
 use crate::MultiTenantNftPlatformRust;
 
+fn emit_collection_owner_proposed(
+    storage: &NeoStorageContext,
+    collection_id: i64,
+    owner_id: i64,
+    pending_owner_id: i64,
+    clear_operators: bool,
+) {
+    let mut state = NeoArray::new();
+    state.push(token_id_value(collection_id));
+    state.push(hash160_value_from_account_id(storage, Some(owner_id)));
+    state.push(hash160_value_from_account_id(storage, Some(pending_owner_id)));
+    state.push(NeoValue::Boolean(NeoBoolean::new(clear_operators)));
+
+    let label = NeoString::from_str("CollectionOwnerProposed");
+    let _ = NeoRuntime::notify(&label, &state);
+}
+
+fn emit_collection_owner_changed(
+    storage: &NeoStorageContext,
+    collection_id: i64,
+    previous_owner_id: i64,
+    new_owner_id: i64,
+    operators_cleared: bool,
+) {
+    let mut state = NeoArray::new();
+    state.push(token_id_value(collection_id));
+    state.push(hash160_value_from_account_id(storage, Some(previous_owner_id)));
+    state.push(hash160_value_from_account_id(storage, Some(new_owner_id)));
+    state.push(NeoValue::Boolean(NeoBoolean::new(operators_cleared)));
+
+    let label = NeoString::from_str("CollectionOwnerChanged");
+    let _ = NeoRuntime::notify(&label, &state);
+}
+
+fn emit_collection_metadata_frozen(collection_id: i64, frozen_at: i64) {
+    let mut state = NeoArray::new();
+    state.push(token_id_value(collection_id));
+    state.push(NeoValue::Integer(NeoInteger::new(frozen_at)));
+
+    let label = NeoString::from_str("CollectionMetadataFrozen");
+    let _ = NeoRuntime::notify(&label, &state);
+}
+
+fn emit_collection_archived(
+    storage: &NeoStorageContext,
+    collection_id: i64,
+    owner_id: i64,
+    owner_slot_released: bool,
+    archived_at: i64,
+) {
+    let mut state = NeoArray::new();
+    state.push(token_id_value(collection_id));
+    state.push(hash160_value_from_account_id(storage, Some(owner_id)));
+    state.push(NeoValue::Boolean(NeoBoolean::new(owner_slot_released)));
+    state.push(NeoValue::Integer(NeoInteger::new(archived_at)));
+
+    let label = NeoString::from_str("CollectionArchived");
+    let _ = NeoRuntime::notify(&label, &state);
+}
+
+fn emit_collection_rename_lock_updated(collection_id: i64, lock_at: i64) {
+    let mut state = NeoArray::new();
+    state.push(token_id_value(collection_id));
+    state.push(NeoValue::Integer(NeoInteger::new(lock_at)));
+
+    let label = NeoString::from_str("CollectionRenameLockUpdated");
+    let _ = NeoRuntime::notify(&label, &state);
+}
+
+fn emit_royalty_splits_updated(collection_id: i64, recipient_count: i64, total_bps: i64) {
+    let mut state = NeoArray::new();
+    state.push(token_id_value(collection_id));
+    state.push(NeoValue::Integer(NeoInteger::new(recipient_count)));
+    state.push(NeoValue::Integer(NeoInteger::new(total_bps)));
+
+    let label = NeoString::from_str("RoyaltySplitsUpdated");
+    let _ = NeoRuntime::notify(&label, &state);
+}
+
+fn emit_dedicated_extra_data_updated(collection_id: i64, size: i64) {
+    let mut state = NeoArray::new();
+    state.push(token_id_value(collection_id));
+    state.push(NeoValue::Integer(NeoInteger::new(size)));
+
+    let label = NeoString::from_str("DedicatedExtraDataUpdated");
+    let _ = NeoRuntime::notify(&label, &state);
+}
+
 #[neo_contract]
 impl MultiTenantNftPlatformRust {
     #[neo_method(
@@ -59,7 +147,7 @@ impl MultiTenantNftPlatformRust {
             return 0;
         }
 
-        if read_i64(&storage, &owner_collection_key(creator_id)) > 0 {
+        if !owner_has_collection_capacity(&storage, creator_id) {
             return 0;
         }
 
@@ -98,7 +186,19 @@ impl MultiTenantNftPlatformRust {
             || !write_bool(&storage, &collection_field_key(collection_id, FIELD_PAUSED), false)
             || !write_i64(&storage, &collection_field_key(collection_id, FIELD_CREATED_AT), now())
             || !write_i64(&storage, &collection_serial_key(collection_id), 0)
-            || !write_i64(&storage, &owner_collection_key(creator_id), collection_id)
+        {
+            return 0;
+        }
+
+        if !ensure_owner_collection_index(&storage, creator_id)
+            || !add_owner_collection_index(&storage, creator_id, collection_id)
+        {
+            return 0;
+        }
+
+        // The dedicated slot keeps pointing at an owner's first collection for legacy single-collection callers.
+        if read_i64(&storage, &owner_collection_key(creator_id)) <= 0
+            && !write_i64(&storage, &owner_collection_key(creator_id), collection_id)
         {
             return 0;
         }
@@ -156,6 +256,22 @@ impl MultiTenantNftPlatformRust {
             return false;
         }
 
+        // Split tables must keep summing to the collection rate, so they have to be cleared before it changes.
+        if royalty_split_count(&storage, collection_id) > 0
+            && read_i64(&storage, &collection_field_key(collection_id, FIELD_ROYALTY_BPS)) != royalty_bps
+        {
+            return false;
+        }
+
+        if is_collection_metadata_frozen(&storage, collection_id)
+            && (read_string_field(&storage, &collection_field_key(collection_id, FIELD_DESC_REF)).as_str()
+                != description.as_str()
+                || read_string_field(&storage, &collection_field_key(collection_id, FIELD_BASE_URI_REF)).as_str()
+                    != base_uri.as_str())
+        {
+            return false;
+        }
+
         let updated = write_string_field(
             &storage,
             &collection_field_key(collection_id, FIELD_DESC_REF),
@@ -182,15 +298,414 @@ impl MultiTenantNftPlatformRust {
         updated
     }
 
+    #[neo_method(
+        name = "renameCollection",
+        param_types = ["Hash160", "ByteArray", "Integer", "Integer"]
+    )]
+    pub fn rename_collection(creator: i64, collection_id: i64, name_ref: i64, symbol_ref: i64) -> bool {
+        if creator <= 0 || collection_id <= 0 {
+            return false;
+        }
+
+        let name = string_ref(name_ref);
+        let symbol = string_ref(symbol_ref);
+        if name.as_str().is_empty() || name.len() > 80 || symbol.as_str().is_empty() || symbol.len() > 12 {
+            return false;
+        }
+
+        let Some(storage) = storage_context() else {
+            return false;
+        };
+
+        let creator_id = canonical_account_id(&storage, creator);
+        if creator_id <= 0 || !check_witness_for_account_ref(&storage, creator) {
+            return false;
+        }
+
+        if !collection_exists(&storage, collection_id) {
+            return false;
+        }
+
+        let owner = read_i64(&storage, &collection_field_key(collection_id, FIELD_OWNER));
+        if owner != creator_id || is_collection_metadata_frozen(&storage, collection_id) {
+            return false;
+        }
+
+        // Renames stay open until the first mint, or until the configured lock time if one was set.
+        let minted = read_i64(&storage, &collection_field_key(collection_id, FIELD_MINTED));
+        let lock_at = read_i64(&storage, &collection_field_key(collection_id, FIELD_RENAME_LOCK_AT));
+        if minted > 0 && (lock_at <= 0 || now() >= lock_at) {
+            return false;
+        }
+
+        let updated = write_string_field(&storage, &collection_field_key(collection_id, FIELD_NAME_REF), &name)
+            && write_string_field(&storage, &collection_field_key(collection_id, FIELD_SYMBOL_REF), &symbol);
+
+        if updated {
+            emit_collection_upserted(&storage, collection_id);
+        }
+
+        updated
+    }
+
+    #[neo_method(
+        name = "setCollectionRenameLock",
+        param_types = ["Hash160", "ByteArray", "Integer"]
+    )]
+    pub fn set_collection_rename_lock(creator: i64, collection_id: i64, lock_at: i64) -> bool {
+        if creator <= 0 || collection_id <= 0 || lock_at < 0 {
+            return false;
+        }
+
+        let Some(storage) = storage_context() else {
+            return false;
+        };
+
+        let creator_id = canonical_account_id(&storage, creator);
+        if creator_id <= 0 || !check_witness_for_account_ref(&storage, creator) {
+            return false;
+        }
+
+        if !collection_exists(&storage, collection_id) {
+            return false;
+        }
+
+        let owner = read_i64(&storage, &collection_field_key(collection_id, FIELD_OWNER));
+        if owner != creator_id {
+            return false;
+        }
+
+        // The lock can only be configured before holders exist, so it cannot be pushed out later.
+        if read_i64(&storage, &collection_field_key(collection_id, FIELD_MINTED)) > 0 {
+            return false;
+        }
+
+        if !write_i64(&storage, &collection_field_key(collection_id, FIELD_RENAME_LOCK_AT), lock_at) {
+            return false;
+        }
+
+        emit_collection_rename_lock_updated(collection_id, lock_at);
+        true
+    }
+
+    #[neo_method(name = "archiveCollection", param_types = ["Hash160", "ByteArray", "Boolean"])]
+    pub fn archive_collection(creator: i64, collection_id: i64, release_owner_slot: bool) -> bool {
+        if creator <= 0 || collection_id <= 0 {
+            return false;
+        }
+
+        let Some(storage) = storage_context() else {
+            return false;
+        };
+
+        let creator_id = canonical_account_id(&storage, creator);
+        if creator_id <= 0 || !check_witness_for_account_ref(&storage, creator) {
+            return false;
+        }
+
+        if !collection_exists(&storage, collection_id) {
+            return false;
+        }
+
+        let owner = read_i64(&storage, &collection_field_key(collection_id, FIELD_OWNER));
+        if owner != creator_id || is_collection_archived(&storage, collection_id) {
+            return false;
+        }
+
+        if !ensure_owner_collection_index(&storage, owner) {
+            return false;
+        }
+
+        // Archival is permanent, so drop and check-in programs are switched off alongside minting.
+        if !write_bool(&storage, &collection_field_key(collection_id, FIELD_ARCHIVED), true)
+            || !write_bool(&storage, &drop_config_key(collection_id, DROP_FIELD_ENABLED), false)
+            || !write_bool(&storage, &checkin_program_key(collection_id, CHECKIN_FIELD_ENABLED), false)
+            || !write_i64(&storage, &collection_field_key(collection_id, FIELD_PENDING_OWNER), 0)
+            || !write_bool(
+                &storage,
+                &collection_field_key(collection_id, FIELD_PENDING_CLEAR_OPERATORS),
+                false,
+            )
+            || !write_i64(
+                &storage,
+                &owner_archived_collection_count_key(owner),
+                owner_archived_collection_count(&storage, owner) + 1,
+            )
+        {
+            return false;
+        }
+
+        let owner_slot_released =
+            release_owner_slot && read_i64(&storage, &owner_collection_key(owner)) == collection_id;
+        if owner_slot_released
+            && (!ensure_owner_collection_index(&storage, owner)
+                || !repoint_owner_collection_slot(&storage, owner, collection_id))
+        {
+            return false;
+        }
+
+        emit_collection_archived(&storage, collection_id, owner, owner_slot_released, now());
+        emit_collection_upserted(&storage, collection_id);
+        true
+    }
+
+    #[neo_method(
+        name = "setRoyaltySplits",
+        param_types = ["Hash160", "ByteArray", "Any", "Any"]
+    )]
+    pub fn set_royalty_splits(creator: i64, collection_id: i64, accounts_ref: i64, bps_ref: i64) -> bool {
+        if creator <= 0 || collection_id <= 0 {
+            return false;
+        }
+
+        let Some(storage) = storage_context() else {
+            return false;
+        };
+
+        let creator_id = canonical_account_id(&storage, creator);
+        if creator_id <= 0 || !check_witness_for_account_ref(&storage, creator) {
+            return false;
+        }
+
+        if !collection_exists(&storage, collection_id) {
+            return false;
+        }
+
+        let owner = read_i64(&storage, &collection_field_key(collection_id, FIELD_OWNER));
+        if owner != creator_id {
+            return false;
+        }
+
+        let Some(NeoValue::Array(accounts)) = neo_devpack::abi::resolve_value(accounts_ref) else {
+            return false;
+        };
+        let Some(NeoValue::Array(shares)) = neo_devpack::abi::resolve_value(bps_ref) else {
+            return false;
+        };
+
+        let recipient_count = accounts.len() as i64;
+        if accounts.len() != shares.len() || recipient_count > MAX_ROYALTY_RECIPIENTS {
+            return false;
+        }
+
+        let mut recipients = Vec::new();
+        let mut total_bps = 0i64;
+        let mut index = 0usize;
+        while index < accounts.len() {
+            let Some(account_value) = accounts.get(index) else {
+                return false;
+            };
+            let Some(bps_value) = shares.get(index) else {
+                return false;
+            };
+
+            let account_id = canonical_account_id(&storage, neo_devpack::abi::i64_from_value(account_value));
+            let bps = neo_devpack::abi::i64_from_value(bps_value);
+            if account_id <= 0
+                || bps <= 0
+                || bps > 10000
+                || recipients.iter().any(|(existing, _)| *existing == account_id)
+            {
+                return false;
+            }
+
+            let Some(next_total) = total_bps.checked_add(bps) else {
+                return false;
+            };
+            total_bps = next_total;
+            recipients.push((account_id, bps));
+            index += 1;
+        }
+
+        // An empty table clears the splits and falls back to paying the collection owner.
+        let royalty_bps = read_i64(&storage, &collection_field_key(collection_id, FIELD_ROYALTY_BPS));
+        if recipient_count > 0 && total_bps != royalty_bps {
+            return false;
+        }
+
+        let previous_count = royalty_split_count(&storage, collection_id);
+        let mut position = 1i64;
+        for (account_id, bps) in recipients.iter() {
+            if !write_i64(
+                &storage,
+                &royalty_split_key(collection_id, position, ROYALTY_SPLIT_FIELD_ACCOUNT),
+                *account_id,
+            ) || !write_i64(&storage, &royalty_split_key(collection_id, position, ROYALTY_SPLIT_FIELD_BPS), *bps)
+            {
+                return false;
+            }
+            position += 1;
+        }
+
+        while position <= previous_count {
+            if !write_i64(&storage, &royalty_split_key(collection_id, position, ROYALTY_SPLIT_FIELD_ACCOUNT), 0)
+                || !write_i64(&storage, &royalty_split_key(collection_id, position, ROYALTY_SPLIT_FIELD_BPS), 0)
+            {
+                return false;
+            }
+            position += 1;
+        }
+
+        if !write_i64(&storage, &royalty_split_count_key(collection_id), recipient_count) {
+            return false;
+        }
+
+        emit_royalty_splits_updated(collection_id, recipient_count, total_bps);
+        true
+    }
+
+    #[neo_method(
+        name = "getRoyaltySplits",
+        safe,
+        param_types = ["ByteArray"],
+        return_type = "Array"
+    )]
+    pub fn get_royalty_splits(collection_id: i64) -> i64 {
+        let mut result = NeoArray::new();
+        let Some(storage) = storage_context() else {
+            return to_iterator_handle(result);
+        };
+
+        if collection_id <= 0 || !collection_exists(&storage, collection_id) {
+            return to_iterator_handle(result);
+        }
+
+        let split_count = royalty_split_count(&storage, collection_id);
+        let mut index = 1;
+        while index <= split_count {
+            let account_id = read_i64(&storage, &royalty_split_key(collection_id, index, ROYALTY_SPLIT_FIELD_ACCOUNT));
+            let bps = read_i64(&storage, &royalty_split_key(collection_id, index, ROYALTY_SPLIT_FIELD_BPS));
+
+            let mut entry = NeoArray::new();
+            entry.push(hash160_value_from_account_id(&storage, Some(account_id)));
+            entry.push(NeoValue::Integer(NeoInteger::new(bps)));
+            result.push(NeoValue::Array(entry));
+            index += 1;
+        }
+
+        to_iterator_handle(result)
+    }
+
+    #[neo_method(name = "freezeCollectionMetadata", param_types = ["Hash160", "ByteArray"])]
+    pub fn freeze_collection_metadata(creator: i64, collection_id: i64) -> bool {
+        if creator <= 0 || collection_id <= 0 {
+            return false;
+        }
+
+        let Some(storage) = storage_context() else {
+            return false;
+        };
+
+        let creator_id = canonical_account_id(&storage, creator);
+        if creator_id <= 0 || !check_witness_for_account_ref(&storage, creator) {
+            return false;
+        }
+
+        if !collection_exists(&storage, collection_id) {
+            return false;
+        }
+
+        let owner = read_i64(&storage, &collection_field_key(collection_id, FIELD_OWNER));
+        if owner != creator_id || is_collection_metadata_frozen(&storage, collection_id) {
+            return false;
+        }
+
+        if !write_bool(&storage, &collection_field_key(collection_id, FIELD_METADATA_FROZEN), true) {
+            return false;
+        }
+
+        emit_collection_metadata_frozen(collection_id, now());
+        true
+    }
+
+    #[neo_method(
+        name = "setDedicatedExtraData",
+        param_types = ["Hash160", "ByteArray", "Any"]
+    )]
+    pub fn set_dedicated_extra_data(creator: i64, collection_id: i64, extra_data_ref: i64) -> bool {
+        if creator <= 0 || collection_id <= 0 {
+            return false;
+        }
+
+        // Null clears the blob; any other value is kept in its StdLib-serialized form, as the C# contract does.
+        let extra_data = match neo_devpack::abi::resolve_value(extra_data_ref) {
+            Some(NeoValue::Null) | None => NeoByteString::from_slice(&[]),
+            Some(value) => match std_serialize(value) {
+                Some(serialized) => serialized,
+                None => return false,
+            },
+        };
+        if extra_data.len() > 4096 {
+            return false;
+        }
+
+        let Some(storage) = storage_context() else {
+            return false;
+        };
+
+        let creator_id = canonical_account_id(&storage, creator);
+        if creator_id <= 0 || !check_witness_for_account_ref(&storage, creator) {
+            return false;
+        }
+
+        if !collection_exists(&storage, collection_id) {
+            return false;
+        }
+
+        let owner = read_i64(&storage, &collection_field_key(collection_id, FIELD_OWNER));
+        if owner != creator_id {
+            return false;
+        }
+
+        if !write_bytes(&storage, &collection_field_key(collection_id, FIELD_EXTRA_DATA), &extra_data) {
+            return false;
+        }
+
+        emit_dedicated_extra_data_updated(collection_id, extra_data.len() as i64);
+        true
+    }
+
+    #[neo_method(
+        name = "getDedicatedExtraData",
+        safe,
+        param_types = ["ByteArray"],
+        return_type = "Any"
+    )]
+    pub fn get_dedicated_extra_data(collection_id: i64) -> i64 {
+        if collection_id <= 0 {
+            return 0;
+        }
+
+        let Some(storage) = storage_context() else {
+            return 0;
+        };
+
+        if !collection_exists(&storage, collection_id) {
+            return 0;
+        }
+
+        match read_bytes(&storage, &collection_field_key(collection_id, FIELD_EXTRA_DATA)) {
+            Some(extra_data) if !extra_data.as_slice().is_empty() => match std_deserialize(&extra_data) {
+                Some(value) => neo_devpack::abi::i64_from_value(&value),
+                None => 0,
+            },
+            _ => 0,
+        }
+    }
+
     #[neo_method(
         name = "setCollectionOperator",
-        param_types = ["Hash160", "ByteArray", "Hash160", "Boolean"]
+        param_types = ["Hash160", "ByteArray", "Hash160", "Integer"]
     )]
-    pub fn set_collection_operator(creator: i64, collection_id: i64, operator: i64, enabled: bool) -> bool {
+    pub fn set_collection_operator(creator: i64, collection_id: i64, operator: i64, permissions: i64) -> bool {
         if creator <= 0 || operator <= 0 || collection_id <= 0 {
             return false;
         }
 
+        if permissions < 0 || permissions & !(OPERATOR_PERMISSION_ALL | OPERATOR_PERMISSION_LEGACY) != 0 {
+            return false;
+        }
+
         let Some(storage) = storage_context() else {
             return false;
         };
@@ -210,9 +725,22 @@ impl MultiTenantNftPlatformRust {
             return false;
         }
 
-        let updated = write_bool(&storage, &operator_key(collection_id, operator_id), enabled);
+        let epoch = read_i64(&storage, &collection_field_key(collection_id, FIELD_OPERATOR_EPOCH));
+        let indexed = if permissions == 0 {
+            unindex_collection_operator(&storage, collection_id, operator_id)
+        } else {
+            index_collection_operator(&storage, collection_id, operator_id)
+        };
+        let updated = write_i64(&storage, &operator_key(collection_id, operator_id), permissions)
+            && write_i64(&storage, &operator_epoch_key(collection_id, operator_id), epoch)
+            && indexed;
         if updated {
-            emit_collection_operator_updated(&storage, collection_id, operator_id, enabled);
+            emit_collection_operator_updated(
+                &storage,
+                collection_id,
+                operator_id,
+                operator_permissions(&storage, collection_id, operator_id),
+            );
         }
         updated
     }
@@ -232,7 +760,120 @@ impl MultiTenantNftPlatformRust {
             return false;
         }
 
-        read_bool(&storage, &operator_key(collection_id, operator_id))
+        operator_permissions(&storage, collection_id, operator_id) != 0
+    }
+
+    #[neo_method(
+        name = "getCollectionOperatorPermissions",
+        safe,
+        param_types = ["ByteArray", "Hash160"]
+    )]
+    pub fn get_collection_operator_permissions(collection_id: i64, operator: i64) -> i64 {
+        if collection_id <= 0 || operator <= 0 {
+            return 0;
+        }
+
+        let Some(storage) = storage_context() else {
+            return 0;
+        };
+
+        let operator_id = canonical_account_id(&storage, operator);
+        if operator_id <= 0 {
+            return 0;
+        }
+
+        operator_permissions(&storage, collection_id, operator_id)
+    }
+
+    #[neo_method(
+        name = "getCollectionOperators",
+        safe,
+        param_types = ["ByteArray", "Integer", "Integer"],
+        return_type = "Array"
+    )]
+    pub fn get_collection_operators(collection_id: i64, offset: i64, limit: i64) -> i64 {
+        let mut result = NeoArray::new();
+        if collection_id <= 0 || offset < 0 || limit <= 0 || limit > MAX_PAGE_SIZE {
+            return to_iterator_handle(result);
+        }
+
+        let Some(storage) = storage_context() else {
+            return to_iterator_handle(result);
+        };
+
+        if !collection_exists(&storage, collection_id) {
+            return to_iterator_handle(result);
+        }
+
+        // Revocations prune the index, so the total only counts accounts holding a grant.
+        let count = read_i64(&storage, &operator_count_key(collection_id));
+        let mut items = NeoArray::new();
+        let mut index = offset.saturating_add(1);
+        let page_end = offset.saturating_add(limit).min(count);
+        while index <= page_end {
+            let operator_id = read_i64(&storage, &operator_index_key(collection_id, index));
+            index += 1;
+
+            let permissions = operator_permissions(&storage, collection_id, operator_id);
+            if operator_id <= 0 || permissions == 0 {
+                continue;
+            }
+
+            let mut entry = NeoArray::new();
+            entry.push(hash160_value_from_account_id(&storage, Some(operator_id)));
+            entry.push(NeoValue::Integer(NeoInteger::new(permissions)));
+            items.push(NeoValue::Array(entry));
+        }
+
+        result.push(NeoValue::Integer(NeoInteger::new(count)));
+        result.push(NeoValue::Array(items));
+        to_iterator_handle(result)
+    }
+
+    // Grants written before the operator index existed are only visible through events, so the collection owner
+    // or platform admin backfills them from a supplied account list; accounts without a live grant are skipped.
+    #[neo_method(name = "backfillCollectionOperators", param_types = ["ByteArray", "Any"])]
+    pub fn backfill_collection_operators(collection_id: i64, operators_ref: i64) -> i64 {
+        let Some(storage) = storage_context() else {
+            return 0;
+        };
+
+        if !collection_exists(&storage, collection_id) {
+            return 0;
+        }
+
+        let owner = read_i64(&storage, &collection_field_key(collection_id, FIELD_OWNER));
+        if !check_witness_for_account_ref(&storage, owner) && !check_platform_admin_witness(&storage) {
+            return 0;
+        }
+
+        let Some(NeoValue::Array(operators)) = neo_devpack::abi::resolve_value(operators_ref) else {
+            return 0;
+        };
+        if operators.len() > MAX_OPERATOR_BACKFILL_SIZE {
+            return 0;
+        }
+
+        let mut indexed = 0;
+        let mut index = 0usize;
+        while index < operators.len() {
+            let Some(operator_value) = operators.get(index) else {
+                return indexed;
+            };
+            index += 1;
+
+            let operator_id = canonical_account_id(&storage, neo_devpack::abi::i64_from_value(operator_value));
+            if operator_id <= 0 || operator_permissions(&storage, collection_id, operator_id) == 0 {
+                continue;
+            }
+
+            if !index_collection_operator(&storage, collection_id, operator_id) {
+                panic!("Failed to index collection operator");
+            }
+            indexed += 1;
+        }
+
+        indexed
     }
 
     #[neo_method(name = "getOwnerDedicatedCollection", safe, param_types = ["Hash160"], return_type = "ByteArray")]
@@ -275,4 +916,210 @@ impl MultiTenantNftPlatformRust {
 
         read_i64(&storage, &owner_collection_key(owner_id)) > 0
     }
+
+    #[neo_method(
+        name = "getCollectionsOf",
+        safe,
+        param_types = ["Hash160"],
+        return_type = "Array"
+    )]
+    pub fn get_collections_of(owner: i64) -> i64 {
+        let mut result = NeoArray::new();
+        if owner <= 0 {
+            return to_iterator_handle(result);
+        }
+
+        let Some(storage) = storage_context() else {
+            return to_iterator_handle(result);
+        };
+
+        let owner_id = canonical_account_id(&storage, owner);
+        if owner_id <= 0 {
+            return to_iterator_handle(result);
+        }
+
+        let count = owner_collection_count(&storage, owner_id);
+        if count <= 0 {
+            let legacy_collection_id = read_i64(&storage, &owner_collection_key(owner_id));
+            if legacy_collection_id > 0 {
+                result.push(token_id_value(legacy_collection_id));
+            }
+            return to_iterator_handle(result);
+        }
+
+        let mut index = 1;
+        while index <= count {
+            let collection_id = read_i64(&storage, &owner_collections_key(owner_id, index));
+            if collection_id > 0 {
+                result.push(token_id_value(collection_id));
+            }
+            index += 1;
+        }
+
+        to_iterator_handle(result)
+    }
+
+    #[neo_method(
+        name = "proposeCollectionOwner",
+        param_types = ["Hash160", "ByteArray", "Hash160", "Boolean"]
+    )]
+    pub fn propose_collection_owner(creator: i64, collection_id: i64, new_owner: i64, clear_operators: bool) -> bool {
+        if creator <= 0 || collection_id <= 0 || new_owner <= 0 {
+            return false;
+        }
+
+        let Some(storage) = storage_context() else {
+            return false;
+        };
+
+        let creator_id = canonical_account_id(&storage, creator);
+        let new_owner_id = canonical_account_id(&storage, new_owner);
+        if creator_id <= 0 || new_owner_id <= 0 || !check_witness_for_account_ref(&storage, creator) {
+            return false;
+        }
+
+        if !collection_exists(&storage, collection_id) || is_collection_archived(&storage, collection_id) {
+            return false;
+        }
+
+        let owner = read_i64(&storage, &collection_field_key(collection_id, FIELD_OWNER));
+        if owner != creator_id {
+            return false;
+        }
+
+        // Proposing the current owner withdraws any pending proposal.
+        let pending_owner_id = if new_owner_id == owner { 0 } else { new_owner_id };
+        let pending_clear_operators = pending_owner_id > 0 && clear_operators;
+        if !write_i64(
+            &storage,
+            &collection_field_key(collection_id, FIELD_PENDING_OWNER),
+            pending_owner_id,
+        ) || !write_bool(
+            &storage,
+            &collection_field_key(collection_id, FIELD_PENDING_CLEAR_OPERATORS),
+            pending_clear_operators,
+        ) {
+            return false;
+        }
+
+        emit_collection_owner_proposed(
+            &storage,
+            collection_id,
+            owner,
+            pending_owner_id,
+            pending_clear_operators,
+        );
+        true
+    }
+
+    #[neo_method(name = "acceptCollectionOwner", param_types = ["Hash160", "ByteArray"])]
+    pub fn accept_collection_owner(new_owner: i64, collection_id: i64) -> bool {
+        if new_owner <= 0 || collection_id <= 0 {
+            return false;
+        }
+
+        let Some(storage) = storage_context() else {
+            return false;
+        };
+
+        let new_owner_id = canonical_account_id(&storage, new_owner);
+        if new_owner_id <= 0 || !check_witness_for_account_ref(&storage, new_owner) {
+            return false;
+        }
+
+        if !collection_exists(&storage, collection_id) || is_collection_archived(&storage, collection_id) {
+            return false;
+        }
+
+        let pending_owner_id = read_i64(&storage, &collection_field_key(collection_id, FIELD_PENDING_OWNER));
+        if pending_owner_id <= 0 || pending_owner_id != new_owner_id {
+            return false;
+        }
+
+        if !owner_has_collection_capacity(&storage, new_owner_id) {
+            return false;
+        }
+
+        let previous_owner_id = read_i64(&storage, &collection_field_key(collection_id, FIELD_OWNER));
+        let clear_operators = read_bool(
+            &storage,
+            &collection_field_key(collection_id, FIELD_PENDING_CLEAR_OPERATORS),
+        );
+
+        if !ensure_owner_collection_index(&storage, previous_owner_id)
+            || !remove_owner_collection_index(&storage, previous_owner_id, collection_id)
+            || !ensure_owner_collection_index(&storage, new_owner_id)
+            || !add_owner_collection_index(&storage, new_owner_id, collection_id)
+        {
+            return false;
+        }
+
+        if !repoint_owner_collection_slot(&storage, previous_owner_id, collection_id) {
+            return false;
+        }
+
+        if read_i64(&storage, &owner_collection_key(new_owner_id)) <= 0
+            && !write_i64(&storage, &owner_collection_key(new_owner_id), collection_id)
+        {
+            return false;
+        }
+
+        if !write_i64(&storage, &collection_field_key(collection_id, FIELD_OWNER), new_owner_id)
+            || !write_i64(&storage, &collection_field_key(collection_id, FIELD_PENDING_OWNER), 0)
+            || !write_bool(
+                &storage,
+                &collection_field_key(collection_id, FIELD_PENDING_CLEAR_OPERATORS),
+                false,
+            )
+        {
+            return false;
+        }
+
+        if clear_operators {
+            let epoch = read_i64(&storage, &collection_field_key(collection_id, FIELD_OPERATOR_EPOCH));
+            if !write_i64(
+                &storage,
+                &collection_field_key(collection_id, FIELD_OPERATOR_EPOCH),
+                epoch + 1,
+            ) || !write_i64(&storage, &operator_count_key(collection_id), 0)
+            {
+                return false;
+            }
+        }
+
+        emit_collection_upserted(&storage, collection_id);
+        emit_collection_owner_changed(
+            &storage,
+            collection_id,
+            previous_owner_id,
+            new_owner_id,
+            clear_operators,
+        );
+        true
+    }
+
+    #[neo_method(
+        name = "getPendingCollectionOwner",
+        safe,
+        param_types = ["ByteArray"],
+        return_type = "Hash160"
+    )]
+    pub fn get_pending_collection_owner(collection_id: i64) -> i64 {
+        if collection_id <= 0 {
+            return 0;
+        }
+
+        let Some(storage) = storage_context() else {
+            return 0;
+        };
+
+        if !collection_exists(&storage, collection_id) {
+            return 0;
+        }
+
+        hash160_ref_from_account_id(
+            &storage,
+            read_i64(&storage, &collection_field_key(collection_id, FIELD_PENDING_OWNER)),
+        )
+    }
 }
@@ -181,12 +181,11 @@ impl MultiTenantNftPlatformRust {
             return false;
         }
 
-        if !collection_exists(&storage, collection_id) {
+        if !collection_exists(&storage, collection_id) || is_collection_archived(&storage, collection_id) {
             return false;
         }
 
-        let owner = read_i64(&storage, &collection_field_key(collection_id, FIELD_OWNER));
-        if owner != creator_id {
+        if !can_manage_collection(&storage, collection_id, creator_id, OPERATOR_PERMISSION_CONFIGURE_DROP) {
             return false;
         }
 
@@ -236,8 +235,7 @@ impl MultiTenantNftPlatformRust {
             return false;
         }
 
-        let owner = read_i64(&storage, &collection_field_key(collection_id, FIELD_OWNER));
-        if owner != creator_id {
+        if !can_manage_collection(&storage, collection_id, creator_id, OPERATOR_PERMISSION_MANAGE_WHITELIST) {
             return false;
         }
 
@@ -276,8 +274,7 @@ impl MultiTenantNftPlatformRust {
             return false;
         }
 
-        let owner = read_i64(&storage, &collection_field_key(collection_id, FIELD_OWNER));
-        if owner != creator_id {
+        if !can_manage_collection(&storage, collection_id, creator_id, OPERATOR_PERMISSION_MANAGE_WHITELIST) {
             return false;
         }
 
@@ -350,7 +347,7 @@ impl MultiTenantNftPlatformRust {
             return 0;
         }
 
-        if read_bool(&storage, &collection_field_key(collection_id, FIELD_PAUSED)) {
+        if !is_collection_active(&storage, collection_id) {
             return 0;
         }
 
@@ -441,7 +438,7 @@ impl MultiTenantNftPlatformRust {
         let remaining = remaining_drop_claims(&storage, collection_id, account_id);
         let claimable_now = enabled
             && drop_claim_window_open(enabled, start_at, end_at)
-            && !read_bool(&storage, &collection_field_key(collection_id, FIELD_PAUSED))
+            && is_collection_active(&storage, collection_id)
             && remaining > 0;
 
         let mut result = NeoArray::new();
@@ -470,7 +467,7 @@ impl MultiTenantNftPlatformRust {
         let (enabled, start_at, end_at, _, _) = get_drop_config(&storage, collection_id);
         enabled
             && drop_claim_window_open(enabled, start_at, end_at)
-            && !read_bool(&storage, &collection_field_key(collection_id, FIELD_PAUSED))
+            && is_collection_active(&storage, collection_id)
             && remaining_drop_claims(&storage, collection_id, account_id) > 0
     }
 }
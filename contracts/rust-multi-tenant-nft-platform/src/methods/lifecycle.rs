@@ -0,0 +1,353 @@
+use neo_devpack::prelude::*;
+
+use crate::constants::*;
+use crate::helpers::*;
+use crate::keys::*;
+use crate::storage_helpers::*;
+use crate::MultiTenantNftPlatformRust;
+
+fn deploy_admin_ref(data_ref: i64) -> i64 {
+    match neo_devpack::abi::resolve_value(data_ref) {
+        Some(NeoValue::ByteString(account_bytes)) if account_bytes.len() == 20 => data_ref,
+        Some(NeoValue::Array(values)) => values
+            .get(0)
+            .map(|value| neo_devpack::abi::i64_from_value(value))
+            .unwrap_or(0),
+        _ => 0,
+    }
+}
+
+fn emit_schema_migration_progress(from_version: i64, to_version: i64, processed: i64, total: i64, completed: bool) {
+    let mut state = NeoArray::new();
+    state.push(NeoValue::Integer(NeoInteger::new(from_version)));
+    state.push(NeoValue::Integer(NeoInteger::new(to_version)));
+    state.push(NeoValue::Integer(NeoInteger::new(processed)));
+    state.push(NeoValue::Integer(NeoInteger::new(total)));
+    state.push(NeoValue::Boolean(NeoBoolean::new(completed)));
+
+    let label = NeoString::from_str("SchemaMigrationProgress");
+    let _ = NeoRuntime::notify(&label, &state);
+}
+
+fn emit_platform_admin_changed(storage: &NeoStorageContext, previous_admin: i64, new_admin: i64) {
+    let mut state = NeoArray::new();
+    state.push(hash160_value_from_account_id(storage, Some(previous_admin)));
+    state.push(hash160_value_from_account_id(storage, Some(new_admin)));
+
+    let label = NeoString::from_str("PlatformAdminChanged");
+    let _ = NeoRuntime::notify(&label, &state);
+}
+
+fn migrate_string_field(storage: &NeoStorageContext, key: &[u8]) -> bool {
+    let Some(bytes) = read_bytes(storage, key) else {
+        return true;
+    };
+
+    if !is_legacy_string_ref(bytes.as_slice()) {
+        return true;
+    }
+
+    write_string_field(storage, key, &string_ref(read_i64(storage, key)))
+}
+
+fn migration_total(storage: &NeoStorageContext, from_version: i64) -> i64 {
+    match from_version {
+        SCHEMA_VERSION_LEGACY_REFS => {
+            read_i64(storage, KEY_COLLECTION_COUNTER) + read_i64(storage, KEY_GLOBAL_TOKEN_COUNTER)
+        }
+        SCHEMA_VERSION_STRING_FIELDS => read_i64(storage, KEY_GLOBAL_TOKEN_COUNTER),
+        _ => 0,
+    }
+}
+
+// Cursor positions cover every collection id first, then every global token index.
+fn migrate_legacy_refs_item(storage: &NeoStorageContext, cursor: i64) -> bool {
+    let collection_total = read_i64(storage, KEY_COLLECTION_COUNTER);
+    if cursor <= collection_total {
+        let collection_id = cursor;
+        if !collection_exists(storage, collection_id) {
+            return true;
+        }
+
+        return migrate_string_field(storage, &collection_field_key(collection_id, FIELD_NAME_REF))
+            && migrate_string_field(storage, &collection_field_key(collection_id, FIELD_SYMBOL_REF))
+            && migrate_string_field(storage, &collection_field_key(collection_id, FIELD_DESC_REF))
+            && migrate_string_field(storage, &collection_field_key(collection_id, FIELD_BASE_URI_REF));
+    }
+
+    let token_id = read_i64(storage, &global_token_key(cursor - collection_total));
+    if token_id <= 0 || !token_exists(storage, token_id) {
+        return true;
+    }
+
+    migrate_string_field(storage, &token_field_key(token_id, TOKEN_FIELD_URI_REF))
+        && migrate_string_field(storage, &token_field_key(token_id, TOKEN_FIELD_PROPERTIES_REF))
+}
+
+// Returns (find entries, owner position) for a pre-upgrade token. Every live or burned token gets its find entries;
+// tokens already moved by a mint or transfer since the upgrade carry an owner position, and burned ones never need one.
+fn owner_index_migration_steps(exists: bool, burned: bool, owner_index: i64, owner: i64) -> (bool, bool) {
+    if !exists {
+        return (false, false);
+    }
+
+    (true, !burned && owner_index <= 0 && owner > 0)
+}
+
+fn migrate_owner_token_index_item(storage: &NeoStorageContext, cursor: i64) -> bool {
+    let token_id = read_i64(storage, &global_token_key(cursor));
+    if token_id <= 0 {
+        return true;
+    }
+
+    let owner = read_i64(storage, &token_field_key(token_id, TOKEN_FIELD_OWNER));
+    let (needs_find_entries, needs_owner_index) = owner_index_migration_steps(
+        token_exists(storage, token_id),
+        read_bool(storage, &token_field_key(token_id, TOKEN_FIELD_BURNED)),
+        read_i64(storage, &token_field_key(token_id, TOKEN_FIELD_OWNER_INDEX)),
+        owner,
+    );
+
+    if needs_find_entries && !add_token_find_entries(storage, token_id) {
+        return false;
+    }
+
+    !needs_owner_index || add_owner_token_index(storage, owner, token_id)
+}
+
+// Last cursor position a batch will process; the migration completes once it reaches the total.
+fn migration_batch_end(cursor: i64, batch_size: i64, total: i64) -> i64 {
+    cursor.saturating_add(batch_size).min(total).max(cursor)
+}
+
+fn migrate_item(storage: &NeoStorageContext, from_version: i64, cursor: i64) -> bool {
+    match from_version {
+        SCHEMA_VERSION_LEGACY_REFS => migrate_legacy_refs_item(storage, cursor),
+        SCHEMA_VERSION_STRING_FIELDS => migrate_owner_token_index_item(storage, cursor),
+        _ => false,
+    }
+}
+
+#[neo_contract]
+impl MultiTenantNftPlatformRust {
+    #[neo_method(name = "_deploy", param_types = ["Any", "Boolean"])]
+    pub fn deploy(data_ref: i64, update: bool) {
+        if update {
+            return;
+        }
+
+        let Some(storage) = storage_context() else {
+            panic!("Storage context unavailable");
+        };
+
+        // Deploy data may carry the platform admin, either as a Hash160 or as the first array item;
+        // otherwise the deploying transaction's sender becomes admin, as in the C# contract.
+        let admin_ref = deploy_admin_ref(data_ref);
+        let admin_id = if admin_ref > 0 {
+            canonical_account_id(&storage, admin_ref)
+        } else {
+            transaction_sender_id(&storage)
+        };
+        if admin_id <= 0 {
+            panic!("Unable to resolve the platform admin");
+        }
+
+        if !write_i64(&storage, KEY_PLATFORM_ADMIN, admin_id)
+            || !write_i64(&storage, KEY_TOTAL_SUPPLY, 0)
+            || !write_i64(&storage, KEY_SCHEMA_VERSION, SCHEMA_VERSION_CURRENT)
+        {
+            panic!("Failed to initialize platform state");
+        }
+    }
+
+    #[neo_method(name = "verify", safe)]
+    pub fn verify() -> bool {
+        let Some(storage) = storage_context() else {
+            return false;
+        };
+
+        check_platform_admin_witness(&storage)
+    }
+
+    #[neo_method(name = "update", param_types = ["ByteArray", "String", "Any"])]
+    pub fn update(nef_ref: i64, manifest_ref: i64, data_ref: i64) {
+        let Some(storage) = storage_context() else {
+            panic!("Storage context unavailable");
+        };
+
+        if !is_direct_invocation() {
+            panic!("Contract-to-contract invocation is not allowed for this method");
+        }
+
+        if !check_platform_admin_witness(&storage) {
+            panic!("No authorization");
+        }
+
+        let Some(NeoValue::ByteString(nef)) = neo_devpack::abi::resolve_value(nef_ref) else {
+            panic!("Invalid NEF file");
+        };
+
+        let mut update_args = NeoArray::new();
+        update_args.push(NeoValue::ByteString(nef));
+        update_args.push(NeoValue::String(string_ref(manifest_ref)));
+        update_args.push(neo_devpack::abi::resolve_value(data_ref).unwrap_or(NeoValue::Null));
+
+        if NeoContractRuntime::call(
+            &contract_management_hash(),
+            &NeoString::from_str("update"),
+            &update_args,
+        )
+        .is_err()
+        {
+            panic!("Contract update failed");
+        }
+    }
+
+    #[neo_method(name = "getPlatformAdmin", safe, return_type = "Hash160")]
+    pub fn get_platform_admin() -> i64 {
+        let Some(storage) = storage_context() else {
+            return 0;
+        };
+
+        hash160_ref_from_account_id(&storage, platform_admin(&storage))
+    }
+
+    // Both the outgoing and incoming admin must sign, so a mistyped hash cannot lock the platform.
+    #[neo_method(name = "setPlatformAdmin", param_types = ["Hash160"])]
+    pub fn set_platform_admin(new_admin: i64) -> bool {
+        if new_admin <= 0 || !is_direct_invocation() {
+            return false;
+        }
+
+        let Some(storage) = storage_context() else {
+            return false;
+        };
+
+        let previous_admin = platform_admin(&storage);
+        let new_admin_id = canonical_account_id(&storage, new_admin);
+        if new_admin_id <= 0 || new_admin_id == previous_admin {
+            return false;
+        }
+
+        if !check_platform_admin_witness(&storage) || !check_witness_for_account_ref(&storage, new_admin) {
+            return false;
+        }
+
+        if !write_i64(&storage, KEY_PLATFORM_ADMIN, new_admin_id) {
+            return false;
+        }
+
+        emit_platform_admin_changed(&storage, previous_admin, new_admin_id);
+        true
+    }
+
+    #[neo_method(name = "migrate", param_types = ["Integer", "Integer"])]
+    pub fn migrate(from_version: i64, batch_size: i64) -> bool {
+        if batch_size <= 0 || batch_size > 500 {
+            return false;
+        }
+
+        let Some(storage) = storage_context() else {
+            return false;
+        };
+
+        if !check_platform_admin_witness(&storage) {
+            return false;
+        }
+
+        if from_version != schema_version(&storage) || from_version >= SCHEMA_VERSION_CURRENT {
+            return false;
+        }
+
+        let total = migration_total(&storage, from_version);
+        let mut cursor = read_i64(&storage, KEY_MIGRATION_CURSOR);
+        let batch_end = migration_batch_end(cursor, batch_size, total);
+        while cursor < batch_end {
+            cursor += 1;
+            if !migrate_item(&storage, from_version, cursor) {
+                // Keep the batch atomic so the persisted cursor never skips a failed item.
+                panic!("Failed to migrate storage item");
+            }
+        }
+
+        let completed = cursor >= total;
+        let persisted = if completed {
+            write_i64(&storage, KEY_MIGRATION_CURSOR, 0)
+                && write_i64(&storage, KEY_SCHEMA_VERSION, from_version + 1)
+        } else {
+            write_i64(&storage, KEY_MIGRATION_CURSOR, cursor)
+        };
+        if !persisted {
+            panic!("Failed to persist migration progress");
+        }
+
+        emit_schema_migration_progress(from_version, from_version + 1, cursor, total, completed);
+        true
+    }
+
+    #[neo_method(name = "getSchemaVersion", safe)]
+    pub fn get_schema_version() -> i64 {
+        let Some(storage) = storage_context() else {
+            return 0;
+        };
+
+        schema_version(&storage)
+    }
+
+    #[neo_method(name = "getMigrationStatus", safe, return_type = "Array")]
+    pub fn get_migration_status() -> i64 {
+        let Some(storage) = storage_context() else {
+            return 0;
+        };
+
+        let version = schema_version(&storage);
+        let mut result = NeoArray::new();
+        result.push(NeoValue::Integer(NeoInteger::new(version)));
+        result.push(NeoValue::Integer(NeoInteger::new(SCHEMA_VERSION_CURRENT)));
+        result.push(NeoValue::Integer(NeoInteger::new(read_i64(&storage, KEY_MIGRATION_CURSOR))));
+        result.push(NeoValue::Integer(NeoInteger::new(migration_total(&storage, version))));
+        to_iterator_handle(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{migration_batch_end, owner_index_migration_steps};
+
+    #[test]
+    fn batches_advance_by_their_size() {
+        assert_eq!(migration_batch_end(0, 100, 250), 100);
+        assert_eq!(migration_batch_end(100, 100, 250), 200);
+    }
+
+    #[test]
+    fn final_batch_stops_at_the_total() {
+        assert_eq!(migration_batch_end(200, 100, 250), 250);
+    }
+
+    #[test]
+    fn cursor_never_moves_backwards() {
+        assert_eq!(migration_batch_end(300, 100, 250), 300);
+        assert_eq!(migration_batch_end(0, 100, 0), 0);
+    }
+
+    #[test]
+    fn unmoved_token_gets_both_indexes() {
+        assert_eq!(owner_index_migration_steps(true, false, 0, 7), (true, true));
+    }
+
+    #[test]
+    fn moved_token_still_gets_find_entries() {
+        assert_eq!(owner_index_migration_steps(true, false, 3, 7), (true, false));
+    }
+
+    #[test]
+    fn burned_token_skips_the_owner_index() {
+        assert_eq!(owner_index_migration_steps(true, true, 0, 7), (true, false));
+    }
+
+    #[test]
+    fn missing_token_is_skipped() {
+        assert_eq!(owner_index_migration_steps(false, false, 0, 7), (false, false));
+    }
+}
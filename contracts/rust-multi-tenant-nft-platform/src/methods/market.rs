@@ -0,0 +1,415 @@
+use neo_devpack::prelude::*;
+
+use crate::constants::*;
+use crate::helpers::*;
+use crate::keys::*;
+use crate::methods::auction::place_auction_bid;
+use crate::methods::offer::place_offer;
+use crate::methods::token::transfer_token;
+use crate::storage_helpers::*;
+use crate::MultiTenantNftPlatformRust;
+
+fn gas_hash() -> NeoByteString {
+    NeoByteString::from_slice(&GAS_TOKEN_HASH)
+}
+
+fn is_gas_caller() -> bool {
+    match NeoRuntime::get_calling_script_hash() {
+        Ok(caller) => caller.as_slice() == GAS_TOKEN_HASH.as_slice(),
+        Err(_) => false,
+    }
+}
+
+// Pays out GAS held by the platform contract itself.
+pub(crate) fn transfer_gas(storage: &NeoStorageContext, to_id: i64, amount: i64) -> bool {
+    if to_id <= 0 || amount <= 0 {
+        return false;
+    }
+
+    let Ok(contract_hash) = NeoRuntime::get_executing_script_hash() else {
+        return false;
+    };
+
+    let mut args = NeoArray::new();
+    args.push(NeoValue::ByteString(contract_hash));
+    args.push(hash160_value_from_account_id(storage, Some(to_id)));
+    args.push(NeoValue::Integer(NeoInteger::new(amount)));
+    args.push(NeoValue::Null);
+
+    matches!(
+        NeoContractRuntime::call(&gas_hash(), &NeoString::from_str("transfer"), &args),
+        Ok(NeoValue::Boolean(paid)) if paid.as_bool()
+    )
+}
+
+fn emit_market_balance_credited(storage: &NeoStorageContext, account: i64, amount: i64, balance: i64) {
+    let mut state = NeoArray::new();
+    state.push(hash160_value_from_account_id(storage, Some(account)));
+    state.push(NeoValue::Integer(NeoInteger::new(amount)));
+    state.push(NeoValue::Integer(NeoInteger::new(balance)));
+
+    let label = NeoString::from_str("MarketBalanceCredited");
+    let _ = NeoRuntime::notify(&label, &state);
+}
+
+fn emit_market_balance_withdrawn(storage: &NeoStorageContext, account: i64, amount: i64) {
+    let mut state = NeoArray::new();
+    state.push(hash160_value_from_account_id(storage, Some(account)));
+    state.push(NeoValue::Integer(NeoInteger::new(amount)));
+
+    let label = NeoString::from_str("MarketBalanceWithdrawn");
+    let _ = NeoRuntime::notify(&label, &state);
+}
+
+// Proceeds are credited rather than pushed, so a recipient that rejects GAS only blocks its own withdrawal.
+pub(crate) fn credit_balance(storage: &NeoStorageContext, account: i64, amount: i64) -> bool {
+    if account <= 0 || amount <= 0 {
+        return false;
+    }
+
+    let Some(balance) = read_i64(storage, &market_balance_key(account)).checked_add(amount) else {
+        return false;
+    };
+    if !write_i64(storage, &market_balance_key(account), balance) {
+        return false;
+    }
+
+    emit_market_balance_credited(storage, account, amount, balance);
+    true
+}
+
+// Royalty recipients first, then whatever is left for the seller; a seller listed as a recipient keeps their share.
+fn split_sale_proceeds(seller: i64, price: i64, payouts: &[(i64, i64)]) -> Option<Vec<(i64, i64)>> {
+    let mut credits = Vec::new();
+    let mut proceeds = price;
+    for (recipient, amount) in payouts.iter().copied() {
+        if recipient == seller || amount <= 0 {
+            continue;
+        }
+
+        if amount > proceeds {
+            return None;
+        }
+        proceeds -= amount;
+        credits.push((recipient, amount));
+    }
+
+    if proceeds > 0 {
+        credits.push((seller, proceeds));
+    }
+    Some(credits)
+}
+
+fn emit_token_listing_updated(
+    storage: &NeoStorageContext,
+    token_id: i64,
+    seller: i64,
+    price: i64,
+    active: bool,
+    listed_at: i64,
+) {
+    let mut state = NeoArray::new();
+    state.push(token_id_value(token_id));
+    state.push(hash160_value_from_account_id(storage, Some(seller)));
+    state.push(NeoValue::Integer(NeoInteger::new(price)));
+    state.push(NeoValue::Boolean(NeoBoolean::new(active)));
+    state.push(NeoValue::Integer(NeoInteger::new(listed_at)));
+
+    let label = NeoString::from_str("TokenListingUpdated");
+    let _ = NeoRuntime::notify(&label, &state);
+}
+
+fn emit_token_sale_matched(storage: &NeoStorageContext, token_id: i64, seller: i64, buyer: i64, price: i64) {
+    let mut state = NeoArray::new();
+    state.push(token_id_value(token_id));
+    state.push(hash160_value_from_account_id(storage, Some(seller)));
+    state.push(hash160_value_from_account_id(storage, Some(buyer)));
+    state.push(NeoValue::Integer(NeoInteger::new(price)));
+    state.push(NeoValue::Integer(NeoInteger::new(now())));
+
+    let label = NeoString::from_str("TokenSaleMatched");
+    let _ = NeoRuntime::notify(&label, &state);
+}
+
+// A listing only counts while its seller still owns the token and the token could actually change hands.
+fn active_listing(storage: &NeoStorageContext, token_id: i64) -> Option<(i64, i64, i64)> {
+    if !token_exists(storage, token_id)
+        || read_bool(storage, &token_field_key(token_id, TOKEN_FIELD_BURNED))
+        || !is_token_tradable(storage, token_id)
+    {
+        return None;
+    }
+
+    let seller = read_i64(storage, &token_listing_key(token_id, LISTING_FIELD_SELLER));
+    let price = read_i64(storage, &token_listing_key(token_id, LISTING_FIELD_PRICE));
+    if seller <= 0 || price <= 0 || seller != read_i64(storage, &token_field_key(token_id, TOKEN_FIELD_OWNER)) {
+        return None;
+    }
+
+    Some((
+        seller,
+        price,
+        read_i64(storage, &token_listing_key(token_id, LISTING_FIELD_LISTED_AT)),
+    ))
+}
+
+pub(crate) fn clear_token_listing(storage: &NeoStorageContext, token_id: i64) -> bool {
+    let seller = read_i64(storage, &token_listing_key(token_id, LISTING_FIELD_SELLER));
+    if seller <= 0 {
+        return true;
+    }
+
+    if !write_i64(storage, &token_listing_key(token_id, LISTING_FIELD_SELLER), 0)
+        || !write_i64(storage, &token_listing_key(token_id, LISTING_FIELD_PRICE), 0)
+        || !write_i64(storage, &token_listing_key(token_id, LISTING_FIELD_LISTED_AT), 0)
+    {
+        return false;
+    }
+
+    emit_token_listing_updated(storage, token_id, seller, 0, false, 0);
+    true
+}
+
+// Everything settle_sale checks before it writes; None means the sale can be refused without side effects.
+pub(crate) fn sale_credits(
+    storage: &NeoStorageContext,
+    token_id: i64,
+    seller: i64,
+    buyer: i64,
+    price: i64,
+) -> Option<Vec<(i64, i64)>> {
+    if seller <= 0 || buyer <= 0 || seller == buyer || price <= 0 || !is_token_tradable(storage, token_id) {
+        return None;
+    }
+
+    let payouts = royalty_payouts(storage, token_id, price)?;
+    split_sale_proceeds(seller, price, &payouts)
+}
+
+// Moves the token first so a blocked transfer never releases funds, then credits royalties and the seller's remainder.
+pub(crate) fn settle_sale(storage: &NeoStorageContext, token_id: i64, seller: i64, buyer: i64, price: i64) -> bool {
+    let Some(credits) = sale_credits(storage, token_id, seller, buyer, price) else {
+        return false;
+    };
+
+    if !transfer_token(storage, buyer, token_id, NeoValue::Null) {
+        return false;
+    }
+
+    for (account, amount) in credits {
+        if !credit_balance(storage, account, amount) {
+            return false;
+        }
+    }
+
+    emit_token_sale_matched(storage, token_id, seller, buyer, price);
+    true
+}
+
+fn buy_listed_token(storage: &NeoStorageContext, buyer: i64, token_id: i64, amount: i64) -> bool {
+    let Some((seller, price, _)) = active_listing(storage, token_id) else {
+        return false;
+    };
+
+    amount == price && settle_sale(storage, token_id, seller, buyer, price)
+}
+
+#[neo_contract]
+impl MultiTenantNftPlatformRust {
+    #[neo_method(name = "listTokenForSale", param_types = ["Hash160", "ByteArray", "Integer"])]
+    pub fn list_token_for_sale(seller: i64, token_id: i64, price: i64) -> bool {
+        if seller <= 0 || token_id <= 0 || price <= 0 {
+            return false;
+        }
+
+        let Some(storage) = storage_context() else {
+            return false;
+        };
+
+        let seller_id = canonical_account_id(&storage, seller);
+        if seller_id <= 0 || !check_witness_for_account_ref(&storage, seller) {
+            return false;
+        }
+
+        if !token_exists(&storage, token_id) || read_bool(&storage, &token_field_key(token_id, TOKEN_FIELD_BURNED)) {
+            return false;
+        }
+
+        if read_i64(&storage, &token_field_key(token_id, TOKEN_FIELD_OWNER)) != seller_id
+            || !is_token_tradable(&storage, token_id)
+        {
+            return false;
+        }
+
+        let listed_at = now();
+        if !write_i64(&storage, &token_listing_key(token_id, LISTING_FIELD_SELLER), seller_id)
+            || !write_i64(&storage, &token_listing_key(token_id, LISTING_FIELD_PRICE), price)
+            || !write_i64(&storage, &token_listing_key(token_id, LISTING_FIELD_LISTED_AT), listed_at)
+        {
+            return false;
+        }
+
+        emit_token_listing_updated(&storage, token_id, seller_id, price, true, listed_at);
+        true
+    }
+
+    #[neo_method(name = "cancelTokenSale", param_types = ["Hash160", "ByteArray"])]
+    pub fn cancel_token_sale(seller: i64, token_id: i64) -> bool {
+        if seller <= 0 || token_id <= 0 {
+            return false;
+        }
+
+        let Some(storage) = storage_context() else {
+            return false;
+        };
+
+        let seller_id = canonical_account_id(&storage, seller);
+        if seller_id <= 0 || !check_witness_for_account_ref(&storage, seller) {
+            return false;
+        }
+
+        let listed_by = read_i64(&storage, &token_listing_key(token_id, LISTING_FIELD_SELLER));
+        if listed_by <= 0 || listed_by != seller_id {
+            return false;
+        }
+
+        clear_token_listing(&storage, token_id)
+    }
+
+    #[neo_method(name = "isTokenListed", safe, param_types = ["ByteArray"])]
+    pub fn is_token_listed(token_id: i64) -> bool {
+        let Some(storage) = storage_context() else {
+            return false;
+        };
+
+        active_listing(&storage, token_id).is_some()
+    }
+
+    #[neo_method(name = "getTokenSale", safe, param_types = ["ByteArray"], return_type = "Array")]
+    pub fn get_token_sale(token_id: i64) -> i64 {
+        let Some(storage) = storage_context() else {
+            return 0;
+        };
+
+        let mut result = NeoArray::new();
+        match active_listing(&storage, token_id) {
+            Some((seller, price, listed_at)) => {
+                result.push(NeoValue::Boolean(NeoBoolean::new(true)));
+                result.push(hash160_value_from_account_id(&storage, Some(seller)));
+                result.push(NeoValue::Integer(NeoInteger::new(price)));
+                result.push(NeoValue::Integer(NeoInteger::new(listed_at)));
+            }
+            None => result.push(NeoValue::Boolean(NeoBoolean::new(false))),
+        }
+        to_iterator_handle(result)
+    }
+
+    #[neo_method(name = "withdraw", param_types = ["Hash160"])]
+    pub fn withdraw(account: i64) -> bool {
+        if account <= 0 {
+            return false;
+        }
+
+        let Some(storage) = storage_context() else {
+            return false;
+        };
+
+        let account_id = canonical_account_id(&storage, account);
+        if account_id <= 0 || !check_witness_for_account_ref(&storage, account) {
+            return false;
+        }
+
+        // The balance is cleared before the transfer so a re-entrant payment callback finds nothing left to take.
+        let amount = read_i64(&storage, &market_balance_key(account_id));
+        if amount <= 0 || !write_i64(&storage, &market_balance_key(account_id), 0) {
+            return false;
+        }
+
+        if !transfer_gas(&storage, account_id, amount) {
+            panic!("Withdrawal transfer failed");
+        }
+
+        emit_market_balance_withdrawn(&storage, account_id, amount);
+        true
+    }
+
+    #[neo_method(name = "getMarketBalance", safe, param_types = ["Hash160"])]
+    pub fn get_market_balance(account: i64) -> i64 {
+        let Some(storage) = storage_context() else {
+            return 0;
+        };
+
+        let account_id = canonical_account_id(&storage, account);
+        if account_id <= 0 {
+            return 0;
+        }
+
+        read_i64(&storage, &market_balance_key(account_id))
+    }
+
+    // GAS payments carry `[operation, target, ...]`; anything the contract cannot match is rejected so the GAS bounces.
+    #[neo_method(name = "onNEP17Payment", param_types = ["Hash160", "Integer", "Any"])]
+    pub fn on_nep17_payment(from: i64, amount: i64, data_ref: i64) {
+        if !is_gas_caller() {
+            panic!("Only GAS payments are accepted");
+        }
+
+        let Some(storage) = storage_context() else {
+            panic!("Storage context unavailable");
+        };
+
+        let from_id = canonical_account_id(&storage, from);
+        if from_id <= 0 || amount <= 0 {
+            panic!("Invalid payment");
+        }
+
+        let Some(NeoValue::Array(values)) = neo_devpack::abi::resolve_value(data_ref) else {
+            panic!("Payment data must describe a market operation");
+        };
+
+        let operation = values.get(0).map(|value| neo_devpack::abi::i64_from_value(value)).unwrap_or(0);
+        let target = values.get(1).map(|value| neo_devpack::abi::i64_from_value(value)).unwrap_or(0);
+        let expires_at = values.get(2).map(|value| neo_devpack::abi::i64_from_value(value)).unwrap_or(0);
+        let accepted = match operation {
+            MARKET_OP_BUY => buy_listed_token(&storage, from_id, target, amount),
+            MARKET_OP_TOKEN_OFFER => place_offer(&storage, OFFER_KIND_TOKEN, from_id, target, amount, expires_at),
+            MARKET_OP_COLLECTION_BID => {
+                place_offer(&storage, OFFER_KIND_COLLECTION, from_id, target, amount, expires_at)
+            }
+            MARKET_OP_AUCTION_BID => place_auction_bid(&storage, from_id, target, amount),
+            _ => false,
+        };
+
+        if !accepted {
+            panic!("Payment rejected");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::split_sale_proceeds;
+
+    #[test]
+    fn seller_receives_the_remainder_after_royalties() {
+        let credits = split_sale_proceeds(1, 1_000, &[(2, 50), (3, 25)]).unwrap();
+        assert_eq!(credits, vec![(2, 50), (3, 25), (1, 925)]);
+    }
+
+    #[test]
+    fn seller_listed_as_recipient_is_paid_once() {
+        let credits = split_sale_proceeds(1, 1_000, &[(1, 100), (2, 50)]).unwrap();
+        assert_eq!(credits, vec![(2, 50), (1, 950)]);
+    }
+
+    #[test]
+    fn royalties_above_the_price_are_rejected() {
+        assert!(split_sale_proceeds(1, 100, &[(2, 60), (3, 60)]).is_none());
+    }
+
+    #[test]
+    fn full_royalty_leaves_nothing_for_the_seller() {
+        let credits = split_sale_proceeds(1, 100, &[(2, 100)]).unwrap();
+        assert_eq!(credits, vec![(2, 100)]);
+    }
+}
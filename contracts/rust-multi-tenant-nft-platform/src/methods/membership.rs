@@ -183,7 +183,7 @@ fn can_checkin_now(storage: &NeoStorageContext, collection_id: i64, account_id:
     let (enabled, membership_required, _, start_at, end_at, interval_seconds, max_per_wallet, _) =
         get_checkin_program(storage, collection_id);
 
-    if !enabled || read_bool(storage, &collection_field_key(collection_id, FIELD_PAUSED)) {
+    if !enabled || !is_collection_active(storage, collection_id) {
         return false;
     }
 
@@ -266,12 +266,11 @@ impl MultiTenantNftPlatformRust {
             return false;
         }
 
-        if !collection_exists(&storage, collection_id) {
+        if !collection_exists(&storage, collection_id) || is_collection_archived(&storage, collection_id) {
             return false;
         }
 
-        let owner = read_i64(&storage, &collection_field_key(collection_id, FIELD_OWNER));
-        if owner != creator_id {
+        if !can_manage_collection(&storage, collection_id, creator_id, OPERATOR_PERMISSION_CONFIGURE_CHECKIN) {
             return false;
         }
 
@@ -335,7 +334,7 @@ impl MultiTenantNftPlatformRust {
             return 0;
         }
 
-        if read_bool(&storage, &collection_field_key(collection_id, FIELD_PAUSED)) {
+        if !is_collection_active(&storage, collection_id) {
             return 0;
         }
 
@@ -1,6 +1,12 @@
+mod auction;
 mod collection;
 mod core;
 mod drop;
+mod lifecycle;
+mod market;
 mod membership;
+mod offer;
+mod platform;
 mod query;
+mod reveal;
 mod token;
@@ -0,0 +1,345 @@
+use neo_devpack::prelude::*;
+
+use crate::constants::*;
+use crate::helpers::*;
+use crate::keys::*;
+use crate::methods::market::{credit_balance, settle_sale, transfer_gas};
+use crate::storage_helpers::*;
+use crate::MultiTenantNftPlatformRust;
+
+fn emit_offer_updated(
+    storage: &NeoStorageContext,
+    kind: u8,
+    target: i64,
+    bidder: i64,
+    amount: i64,
+    active: bool,
+    expires_at: i64,
+) {
+    let mut state = NeoArray::new();
+    state.push(token_id_value(target));
+    state.push(hash160_value_from_account_id(storage, Some(bidder)));
+    state.push(NeoValue::Integer(NeoInteger::new(amount)));
+    state.push(NeoValue::Boolean(NeoBoolean::new(active)));
+    state.push(NeoValue::Integer(NeoInteger::new(expires_at)));
+
+    let label = if kind == OFFER_KIND_TOKEN {
+        NeoString::from_str("TokenOfferUpdated")
+    } else {
+        NeoString::from_str("CollectionBidUpdated")
+    };
+    let _ = NeoRuntime::notify(&label, &state);
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum OfferStatus {
+    Missing,
+    Open,
+    Expired,
+}
+
+fn offer_status(amount: i64, expires_at: i64, current_time: i64) -> OfferStatus {
+    if amount <= 0 {
+        OfferStatus::Missing
+    } else if expires_at > current_time {
+        OfferStatus::Open
+    } else {
+        OfferStatus::Expired
+    }
+}
+
+fn read_offer(storage: &NeoStorageContext, kind: u8, target: i64, bidder: i64) -> (i64, i64) {
+    (
+        read_i64(storage, &market_offer_key(kind, target, bidder, OFFER_FIELD_AMOUNT)),
+        read_i64(storage, &market_offer_key(kind, target, bidder, OFFER_FIELD_EXPIRES_AT)),
+    )
+}
+
+fn clear_offer(storage: &NeoStorageContext, kind: u8, target: i64, bidder: i64) -> bool {
+    if !write_i64(storage, &market_offer_key(kind, target, bidder, OFFER_FIELD_AMOUNT), 0)
+        || !write_i64(storage, &market_offer_key(kind, target, bidder, OFFER_FIELD_EXPIRES_AT), 0)
+        || !write_i64(storage, &market_offer_key(kind, target, bidder, OFFER_FIELD_CREATED_AT), 0)
+        || !delete_key(storage, &find_market_offer_key(kind, target, bidder))
+    {
+        return false;
+    }
+
+    emit_offer_updated(storage, kind, target, bidder, 0, false, 0);
+    true
+}
+
+// Called from onNEP17Payment once the escrowed GAS has already arrived.
+pub(crate) fn place_offer(
+    storage: &NeoStorageContext,
+    kind: u8,
+    bidder: i64,
+    target: i64,
+    amount: i64,
+    expires_at: i64,
+) -> bool {
+    if bidder <= 0 || target <= 0 || amount <= 0 || expires_at <= now() {
+        return false;
+    }
+
+    let open = match kind {
+        OFFER_KIND_TOKEN => {
+            token_exists(storage, target)
+                && !read_bool(storage, &token_field_key(target, TOKEN_FIELD_BURNED))
+                && read_i64(storage, &token_field_key(target, TOKEN_FIELD_OWNER)) != bidder
+                && is_token_tradable(storage, target)
+        }
+        OFFER_KIND_COLLECTION => {
+            collection_exists(storage, target)
+                && !is_platform_paused(storage)
+                && !read_bool(storage, &collection_field_key(target, FIELD_PAUSED))
+                && read_bool(storage, &collection_field_key(target, FIELD_TRANSFERABLE))
+        }
+        _ => false,
+    };
+    if !open {
+        return false;
+    }
+
+    // One escrow per bidder and target; raising an offer means cancelling the old one first.
+    let (existing, _) = read_offer(storage, kind, target, bidder);
+    if existing > 0 {
+        return false;
+    }
+
+    if !write_i64(storage, &market_offer_key(kind, target, bidder, OFFER_FIELD_AMOUNT), amount)
+        || !write_i64(storage, &market_offer_key(kind, target, bidder, OFFER_FIELD_EXPIRES_AT), expires_at)
+        || !write_i64(storage, &market_offer_key(kind, target, bidder, OFFER_FIELD_CREATED_AT), now())
+        || !write_bytes(
+            storage,
+            &find_market_offer_key(kind, target, bidder),
+            &account_hash160(storage, bidder),
+        )
+    {
+        return false;
+    }
+
+    emit_offer_updated(storage, kind, target, bidder, amount, true, expires_at);
+    true
+}
+
+// Expired offers can still be cancelled by the bidder, or refunded by anyone through refund_expired_offer.
+fn cancel_offer(kind: u8, bidder: i64, target: i64) -> bool {
+    if bidder <= 0 || target <= 0 {
+        return false;
+    }
+
+    let Some(storage) = storage_context() else {
+        return false;
+    };
+
+    let bidder_id = canonical_account_id(&storage, bidder);
+    if bidder_id <= 0 || !check_witness_for_account_ref(&storage, bidder) {
+        return false;
+    }
+
+    let (amount, _) = read_offer(&storage, kind, target, bidder_id);
+    if amount <= 0 || !clear_offer(&storage, kind, target, bidder_id) {
+        return false;
+    }
+
+    if !transfer_gas(&storage, bidder_id, amount) {
+        panic!("Offer refund failed");
+    }
+    true
+}
+
+fn accept_offer(kind: u8, seller: i64, token_id: i64, bidder: i64) -> bool {
+    if seller <= 0 || token_id <= 0 || bidder <= 0 {
+        return false;
+    }
+
+    let Some(storage) = storage_context() else {
+        return false;
+    };
+
+    let seller_id = canonical_account_id(&storage, seller);
+    let bidder_id = canonical_account_id(&storage, bidder);
+    if seller_id <= 0 || bidder_id <= 0 || !check_witness_for_account_ref(&storage, seller) {
+        return false;
+    }
+
+    if !token_exists(&storage, token_id)
+        || read_bool(&storage, &token_field_key(token_id, TOKEN_FIELD_BURNED))
+        || read_i64(&storage, &token_field_key(token_id, TOKEN_FIELD_OWNER)) != seller_id
+        || !is_token_tradable(&storage, token_id)
+    {
+        return false;
+    }
+
+    let target = if kind == OFFER_KIND_TOKEN {
+        token_id
+    } else {
+        read_i64(&storage, &token_field_key(token_id, TOKEN_FIELD_COLLECTION_ID))
+    };
+
+    let (amount, expires_at) = read_offer(&storage, kind, target, bidder_id);
+    if offer_status(amount, expires_at, now()) != OfferStatus::Open || seller_id == bidder_id {
+        return false;
+    }
+
+    // The escrow is released before settlement so a receiver callback cannot accept it twice. Payouts go through
+    // royalty_payouts like every other sale, so per-token overrides and collection splits apply; without either it
+    // pays FIELD_ROYALTY_BPS to the collection owner.
+    if !clear_offer(&storage, kind, target, bidder_id) {
+        return false;
+    }
+
+    if !settle_sale(&storage, token_id, seller_id, bidder_id, amount) {
+        panic!("Offer settlement failed");
+    }
+    true
+}
+
+// Once an offer has expired anyone may release its escrow; the GAS is credited, never pushed, to the bidder.
+fn refund_expired_offer(kind: u8, target: i64, bidder: i64) -> bool {
+    if target <= 0 || bidder <= 0 {
+        return false;
+    }
+
+    let Some(storage) = storage_context() else {
+        return false;
+    };
+
+    let bidder_id = canonical_account_id(&storage, bidder);
+    if bidder_id <= 0 {
+        return false;
+    }
+
+    let (amount, expires_at) = read_offer(&storage, kind, target, bidder_id);
+    if offer_status(amount, expires_at, now()) != OfferStatus::Expired {
+        return false;
+    }
+
+    clear_offer(&storage, kind, target, bidder_id) && credit_balance(&storage, bidder_id, amount)
+}
+
+fn offer_to_array(storage: &NeoStorageContext, kind: u8, target: i64, bidder: i64) -> i64 {
+    let (amount, expires_at) = read_offer(storage, kind, target, bidder);
+
+    let mut result = NeoArray::new();
+    result.push(NeoValue::Boolean(NeoBoolean::new(
+        offer_status(amount, expires_at, now()) == OfferStatus::Open,
+    )));
+    result.push(NeoValue::Integer(NeoInteger::new(amount)));
+    result.push(NeoValue::Integer(NeoInteger::new(expires_at)));
+    result.push(NeoValue::Integer(NeoInteger::new(read_i64(
+        storage,
+        &market_offer_key(kind, target, bidder, OFFER_FIELD_CREATED_AT),
+    ))));
+    to_iterator_handle(result)
+}
+
+#[neo_contract]
+impl MultiTenantNftPlatformRust {
+    #[neo_method(name = "cancelTokenOffer", param_types = ["Hash160", "ByteArray"])]
+    pub fn cancel_token_offer(bidder: i64, token_id: i64) -> bool {
+        cancel_offer(OFFER_KIND_TOKEN, bidder, token_id)
+    }
+
+    #[neo_method(name = "cancelCollectionBid", param_types = ["Hash160", "ByteArray"])]
+    pub fn cancel_collection_bid(bidder: i64, collection_id: i64) -> bool {
+        cancel_offer(OFFER_KIND_COLLECTION, bidder, collection_id)
+    }
+
+    #[neo_method(name = "refundExpiredTokenOffer", param_types = ["ByteArray", "Hash160"])]
+    pub fn refund_expired_token_offer(token_id: i64, bidder: i64) -> bool {
+        refund_expired_offer(OFFER_KIND_TOKEN, token_id, bidder)
+    }
+
+    #[neo_method(name = "refundExpiredCollectionBid", param_types = ["ByteArray", "Hash160"])]
+    pub fn refund_expired_collection_bid(collection_id: i64, bidder: i64) -> bool {
+        refund_expired_offer(OFFER_KIND_COLLECTION, collection_id, bidder)
+    }
+
+    #[neo_method(name = "acceptTokenOffer", param_types = ["Hash160", "ByteArray", "Hash160"])]
+    pub fn accept_token_offer(seller: i64, token_id: i64, bidder: i64) -> bool {
+        accept_offer(OFFER_KIND_TOKEN, seller, token_id, bidder)
+    }
+
+    #[neo_method(name = "acceptCollectionBid", param_types = ["Hash160", "ByteArray", "Hash160"])]
+    pub fn accept_collection_bid(seller: i64, token_id: i64, bidder: i64) -> bool {
+        accept_offer(OFFER_KIND_COLLECTION, seller, token_id, bidder)
+    }
+
+    #[neo_method(
+        name = "getTokenOffer",
+        safe,
+        param_types = ["ByteArray", "Hash160"],
+        return_type = "Array"
+    )]
+    pub fn get_token_offer(token_id: i64, bidder: i64) -> i64 {
+        let Some(storage) = storage_context() else {
+            return 0;
+        };
+
+        offer_to_array(&storage, OFFER_KIND_TOKEN, token_id, canonical_account_id(&storage, bidder))
+    }
+
+    #[neo_method(
+        name = "getCollectionBid",
+        safe,
+        param_types = ["ByteArray", "Hash160"],
+        return_type = "Array"
+    )]
+    pub fn get_collection_bid(collection_id: i64, bidder: i64) -> i64 {
+        let Some(storage) = storage_context() else {
+            return 0;
+        };
+
+        offer_to_array(
+            &storage,
+            OFFER_KIND_COLLECTION,
+            collection_id,
+            canonical_account_id(&storage, bidder),
+        )
+    }
+
+    #[neo_method(
+        name = "getTokenOffers",
+        safe,
+        param_types = ["ByteArray"],
+        return_type = "InteropInterface"
+    )]
+    pub fn get_token_offers(token_id: i64) -> i64 {
+        let Some(storage) = storage_context() else {
+            return 0;
+        };
+
+        find_values(&storage, &find_market_offer_prefix(OFFER_KIND_TOKEN, token_id))
+    }
+
+    #[neo_method(
+        name = "getCollectionBids",
+        safe,
+        param_types = ["ByteArray"],
+        return_type = "InteropInterface"
+    )]
+    pub fn get_collection_bids(collection_id: i64) -> i64 {
+        let Some(storage) = storage_context() else {
+            return 0;
+        };
+
+        find_values(&storage, &find_market_offer_prefix(OFFER_KIND_COLLECTION, collection_id))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{offer_status, OfferStatus};
+
+    #[test]
+    fn empty_escrow_is_missing() {
+        assert_eq!(offer_status(0, 2_000, 1_000), OfferStatus::Missing);
+    }
+
+    #[test]
+    fn offer_is_open_until_its_expiry() {
+        assert_eq!(offer_status(10, 2_000, 1_999), OfferStatus::Open);
+        assert_eq!(offer_status(10, 2_000, 2_000), OfferStatus::Expired);
+    }
+}
@@ -0,0 +1,85 @@
+use neo_devpack::prelude::*;
+
+use crate::constants::*;
+use crate::helpers::*;
+use crate::storage_helpers::*;
+use crate::MultiTenantNftPlatformRust;
+
+fn emit_platform_paused(storage: &NeoStorageContext, paused: bool, admin_id: i64, updated_at: i64) {
+    let mut state = NeoArray::new();
+    state.push(NeoValue::Boolean(NeoBoolean::new(paused)));
+    state.push(hash160_value_from_account_id(storage, Some(admin_id)));
+    state.push(NeoValue::Integer(NeoInteger::new(updated_at)));
+
+    let label = NeoString::from_str("PlatformPaused");
+    let _ = NeoRuntime::notify(&label, &state);
+}
+
+fn emit_max_collections_per_owner_updated(max_per_owner: i64) {
+    let mut state = NeoArray::new();
+    state.push(NeoValue::Integer(NeoInteger::new(max_per_owner)));
+
+    let label = NeoString::from_str("MaxCollectionsPerOwnerUpdated");
+    let _ = NeoRuntime::notify(&label, &state);
+}
+
+#[neo_contract]
+impl MultiTenantNftPlatformRust {
+    #[neo_method(name = "setPlatformPaused", param_types = ["Boolean"])]
+    pub fn set_platform_paused(paused: bool) -> bool {
+        let Some(storage) = storage_context() else {
+            return false;
+        };
+
+        if !check_platform_admin_witness(&storage) {
+            return false;
+        }
+
+        if !write_bool(&storage, KEY_PLATFORM_PAUSED, paused) {
+            return false;
+        }
+
+        emit_platform_paused(&storage, paused, platform_admin(&storage), now());
+        true
+    }
+
+    #[neo_method(name = "isPlatformPaused", safe)]
+    pub fn is_platform_paused() -> bool {
+        let Some(storage) = storage_context() else {
+            return false;
+        };
+
+        is_platform_paused(&storage)
+    }
+
+    #[neo_method(name = "setMaxCollectionsPerOwner", param_types = ["Integer"])]
+    pub fn set_max_collections_per_owner(max_per_owner: i64) -> bool {
+        if max_per_owner <= 0 || max_per_owner > MAX_COLLECTIONS_PER_OWNER_LIMIT {
+            return false;
+        }
+
+        let Some(storage) = storage_context() else {
+            return false;
+        };
+
+        if !check_platform_admin_witness(&storage) {
+            return false;
+        }
+
+        if !write_i64(&storage, KEY_MAX_COLLECTIONS_PER_OWNER, max_per_owner) {
+            return false;
+        }
+
+        emit_max_collections_per_owner_updated(max_per_owner);
+        true
+    }
+
+    #[neo_method(name = "getMaxCollectionsPerOwner", safe)]
+    pub fn get_max_collections_per_owner() -> i64 {
+        let Some(storage) = storage_context() else {
+            return 1;
+        };
+
+        max_collections_per_owner(&storage)
+    }
+}
@@ -6,6 +6,13 @@ use crate::keys::*;
 use crate::storage_helpers::*;
 use crate::MultiTenantNftPlatformRust;
 
+fn paged_result(total: i64, items: NeoArray<NeoValue>) -> i64 {
+    let mut result = NeoArray::new();
+    result.push(NeoValue::Integer(NeoInteger::new(total)));
+    result.push(NeoValue::Array(items));
+    to_iterator_handle(result)
+}
+
 #[neo_contract]
 impl MultiTenantNftPlatformRust {
     #[neo_method(name = "tokens", safe, return_type = "InteropInterface")]
@@ -14,6 +21,10 @@ impl MultiTenantNftPlatformRust {
             return 0;
         };
 
+        if is_owner_token_index_ready(&storage) {
+            return find_values(&storage, &find_token_prefix());
+        }
+
         to_iterator_handle(collect_active_tokens(&storage, None, None))
     }
 
@@ -27,15 +38,19 @@ impl MultiTenantNftPlatformRust {
             return 0;
         };
 
-        let total = read_i64(&storage, KEY_GLOBAL_TOKEN_COUNTER);
-        if index > total {
+        // After the schema-3 migration the index counts live tokens in id order.
+        let token_id = if is_owner_token_index_ready(&storage) {
+            find_id_at(&storage, &find_token_prefix(), index)
+        } else if index <= read_i64(&storage, KEY_GLOBAL_TOKEN_COUNTER) {
+            read_i64(&storage, &global_token_key(index))
+        } else {
+            0
+        };
+        if token_id <= 0 {
             return 0;
         }
 
-        neo_devpack::abi::i64_from_bytes(&neo_devpack::abi::bytes_from_i64(read_i64(
-            &storage,
-            &global_token_key(index),
-        )))
+        neo_devpack::abi::i64_from_bytes(&neo_devpack::abi::bytes_from_i64(token_id))
     }
 
     #[neo_method(
@@ -58,9 +73,15 @@ impl MultiTenantNftPlatformRust {
             return 0;
         }
 
+        if is_owner_token_index_ready(&storage) {
+            return find_values(&storage, &find_owner_token_prefix(owner_id));
+        }
+
         to_iterator_handle(collect_active_tokens(&storage, Some(owner_id), None))
     }
 
+    // After the schema-3 migration positions follow the ascending-id order of tokensOf. Before it, a transfer out
+    // moves the owner's last token into the freed slot, so positions are not stable.
     #[neo_method(
         name = "tokenOfByIndex",
         safe,
@@ -81,6 +102,15 @@ impl MultiTenantNftPlatformRust {
             return 0;
         }
 
+        if is_owner_token_index_ready(&storage) {
+            let token_id = find_id_at(&storage, &find_owner_token_prefix(owner_id), index);
+            if token_id <= 0 {
+                return 0;
+            }
+
+            return neo_devpack::abi::i64_from_bytes(&neo_devpack::abi::bytes_from_i64(token_id));
+        }
+
         let tokens = collect_active_tokens(&storage, Some(owner_id), None);
         let target = (index - 1) as usize;
         tokens
@@ -105,6 +135,10 @@ impl MultiTenantNftPlatformRust {
             return 0;
         };
 
+        if is_owner_token_index_ready(&storage) {
+            return find_values(&storage, &find_collection_token_prefix(collection_id));
+        }
+
         let total_serial = read_i64(&storage, &collection_serial_key(collection_id));
         let mut result = NeoArray::new();
         let mut serial = 1;
@@ -120,6 +154,112 @@ impl MultiTenantNftPlatformRust {
         to_iterator_handle(result)
     }
 
+    // All three paged queries take the last token id seen (0 for the first page) instead of an offset, so burns
+    // and transfers between calls never skip or repeat a token. The reply stays `[total, items]`.
+    #[neo_method(
+        name = "tokensPaged",
+        safe,
+        param_types = ["ByteArray", "Integer"],
+        return_type = "Array"
+    )]
+    pub fn tokens_paged(after_token_id: i64, limit: i64) -> i64 {
+        if after_token_id < 0 || limit <= 0 || limit > MAX_PAGE_SIZE {
+            return paged_result(0, NeoArray::new());
+        }
+
+        let Some(storage) = storage_context() else {
+            return paged_result(0, NeoArray::new());
+        };
+
+        let total = read_i64(&storage, KEY_TOTAL_SUPPLY);
+        if !is_owner_token_index_ready(&storage) {
+            return paged_result(total, collect_active_token_page(&storage, None, after_token_id, limit));
+        }
+
+        let mut items = NeoArray::new();
+        for token_id in find_ids_after(&storage, &find_token_prefix(), after_token_id, limit) {
+            items.push(token_id_value(token_id));
+        }
+        paged_result(total, items)
+    }
+
+    #[neo_method(
+        name = "tokensOfPaged",
+        safe,
+        param_types = ["Hash160", "ByteArray", "Integer"],
+        return_type = "Array"
+    )]
+    pub fn tokens_of_paged(owner: i64, after_token_id: i64, limit: i64) -> i64 {
+        if owner <= 0 || after_token_id < 0 || limit <= 0 || limit > MAX_PAGE_SIZE {
+            return paged_result(0, NeoArray::new());
+        }
+
+        let Some(storage) = storage_context() else {
+            return paged_result(0, NeoArray::new());
+        };
+
+        let owner_id = canonical_account_id(&storage, owner);
+        if owner_id <= 0 {
+            return paged_result(0, NeoArray::new());
+        }
+
+        let total = load_balance(&storage, owner_id);
+        if !is_owner_token_index_ready(&storage) {
+            return paged_result(
+                total,
+                collect_active_token_page(&storage, Some(owner_id), after_token_id, limit),
+            );
+        }
+
+        let mut items = NeoArray::new();
+        for token_id in find_ids_after(&storage, &find_owner_token_prefix(owner_id), after_token_id, limit) {
+            items.push(token_id_value(token_id));
+        }
+        paged_result(total, items)
+    }
+
+    #[neo_method(
+        name = "getCollectionTokensPaged",
+        safe,
+        param_types = ["ByteArray", "ByteArray", "Integer"],
+        return_type = "Array"
+    )]
+    pub fn get_collection_tokens_paged(collection_id: i64, after_token_id: i64, limit: i64) -> i64 {
+        if collection_id <= 0 || after_token_id < 0 || limit <= 0 || limit > MAX_PAGE_SIZE {
+            return paged_result(0, NeoArray::new());
+        }
+
+        let Some(first_token_id) = collection_id.checked_mul(TOKEN_SERIAL_FACTOR) else {
+            return paged_result(0, NeoArray::new());
+        };
+        let after_serial = if after_token_id == 0 { 0 } else { after_token_id - first_token_id };
+        if !(0..TOKEN_SERIAL_FACTOR).contains(&after_serial) {
+            return paged_result(0, NeoArray::new());
+        }
+
+        let Some(storage) = storage_context() else {
+            return paged_result(0, NeoArray::new());
+        };
+
+        // Serial order never changes and burned tokens stay listed, matching getCollectionTokens.
+        let total_serial = read_i64(&storage, &collection_serial_key(collection_id));
+        let mut items = NeoArray::new();
+        if after_serial >= total_serial {
+            return paged_result(total_serial, items);
+        }
+
+        let mut serial = after_serial + 1;
+        while serial <= total_serial && (items.len() as i64) < limit {
+            let token_id = first_token_id + serial;
+            if token_exists(&storage, token_id) {
+                items.push(token_id_value(token_id));
+            }
+            serial += 1;
+        }
+
+        paged_result(total_serial, items)
+    }
+
     #[neo_method(
         name = "getCollectionTokenBySerial",
         safe,
@@ -127,7 +267,7 @@ impl MultiTenantNftPlatformRust {
         return_type = "ByteArray"
     )]
     pub fn get_collection_token_by_serial(collection_id: i64, serial: i64) -> i64 {
-        if collection_id <= 0 || serial <= 0 {
+        if collection_id <= 0 || serial <= 0 || serial >= TOKEN_SERIAL_FACTOR {
             return 0;
         }
 
@@ -135,8 +275,9 @@ impl MultiTenantNftPlatformRust {
             return 0;
         };
 
-        let token_id = read_i64(&storage, &collection_token_key(collection_id, serial));
-        if token_id <= 0 {
+        // Token ids are derived from the serial, so the legacy per-collection list is not needed here.
+        let token_id = collection_id * TOKEN_SERIAL_FACTOR + serial;
+        if !token_exists(&storage, token_id) {
             return 0;
         }
 
@@ -160,6 +301,54 @@ impl MultiTenantNftPlatformRust {
         to_iterator_handle(collection_to_array(&storage, collection_id))
     }
 
+    #[neo_method(name = "collectionCount", safe)]
+    pub fn collection_count() -> i64 {
+        let Some(storage) = storage_context() else {
+            return 0;
+        };
+
+        read_i64(&storage, KEY_COLLECTION_COUNTER)
+    }
+
+    #[neo_method(
+        name = "listCollections",
+        safe,
+        param_types = ["Integer", "Integer"],
+        return_type = "Array"
+    )]
+    pub fn list_collections(offset: i64, limit: i64) -> i64 {
+        if offset < 0 || limit <= 0 || limit > MAX_PAGE_SIZE {
+            return to_iterator_handle(NeoArray::new());
+        }
+
+        let Some(storage) = storage_context() else {
+            return to_iterator_handle(NeoArray::new());
+        };
+
+        to_iterator_handle(collect_collections(&storage, offset, limit, None, None))
+    }
+
+    #[neo_method(
+        name = "listCollectionsFiltered",
+        safe,
+        param_types = ["Integer", "Integer", "Integer", "Any"],
+        return_type = "Array"
+    )]
+    pub fn list_collections_filtered(offset: i64, limit: i64, paused_filter: i64, owner_ref: i64) -> i64 {
+        // pausedFilter: -1 matches every collection, 0 only active ones, 1 only paused ones.
+        if offset < 0 || limit <= 0 || limit > MAX_PAGE_SIZE || !(-1..=1).contains(&paused_filter) {
+            return to_iterator_handle(NeoArray::new());
+        }
+
+        let Some(storage) = storage_context() else {
+            return to_iterator_handle(NeoArray::new());
+        };
+
+        let paused = if paused_filter < 0 { None } else { Some(paused_filter == 1) };
+        let owner_filter = optional_account_id(&storage, owner_ref);
+        to_iterator_handle(collect_collections(&storage, offset, limit, paused, owner_filter))
+    }
+
     #[neo_method(name = "getCollectionField", safe, param_types = ["ByteArray", "Integer"])]
     pub fn get_collection_field(collection_id: i64, field_code: i64) -> i64 {
         if collection_id <= 0 {
@@ -213,6 +402,21 @@ impl MultiTenantNftPlatformRust {
                 }
             }
             11 => read_i64(&storage, &collection_field_key(collection_id, FIELD_CREATED_AT)),
+            12 => {
+                if is_collection_metadata_frozen(&storage, collection_id) {
+                    1
+                } else {
+                    0
+                }
+            }
+            13 => read_i64(&storage, &collection_field_key(collection_id, FIELD_RENAME_LOCK_AT)),
+            14 => {
+                if is_collection_archived(&storage, collection_id) {
+                    1
+                } else {
+                    0
+                }
+            }
             _ => 0,
         }
     }
@@ -254,9 +458,10 @@ impl MultiTenantNftPlatformRust {
                 &storage,
                 read_i64(&storage, &token_field_key(token_id, TOKEN_FIELD_OWNER)),
             ),
-            3 => neo_devpack::abi::i64_from_value(&NeoValue::String(read_string_field(
+            3 => neo_devpack::abi::i64_from_value(&NeoValue::String(resolve_token_uri(
                 &storage,
-                &token_field_key(token_id, TOKEN_FIELD_URI_REF),
+                token_id,
+                read_string_field(&storage, &token_field_key(token_id, TOKEN_FIELD_URI_REF)),
             ))),
             4 => neo_devpack::abi::i64_from_value(&NeoValue::String(read_string_field(
                 &storage,
@@ -271,6 +476,14 @@ impl MultiTenantNftPlatformRust {
             }
             6 => read_i64(&storage, &token_field_key(token_id, TOKEN_FIELD_MINTED_AT)),
             7 => read_i64(&storage, &token_field_key(token_id, TOKEN_FIELD_CLASS)),
+            8 => {
+                if is_token_metadata_frozen(&storage, token_id) {
+                    1
+                } else {
+                    0
+                }
+            }
+            9 => read_i64(&storage, &token_field_key(token_id, TOKEN_FIELD_METADATA_VERSION)),
             _ => 0,
         }
     }
@@ -285,7 +498,8 @@ impl MultiTenantNftPlatformRust {
             return NeoString::from_str("");
         }
 
-        read_string_field(&storage, &token_field_key(token_id, TOKEN_FIELD_URI_REF))
+        let stored_uri = read_string_field(&storage, &token_field_key(token_id, TOKEN_FIELD_URI_REF));
+        resolve_token_uri(&storage, token_id, stored_uri)
     }
 
     #[neo_method(
@@ -307,7 +521,11 @@ impl MultiTenantNftPlatformRust {
         let collection_name = read_string_field(&storage, &collection_field_key(collection_id, FIELD_NAME_REF));
         let collection_description =
             read_string_field(&storage, &collection_field_key(collection_id, FIELD_DESC_REF));
-        let uri = read_string_field(&storage, &token_field_key(token_id, TOKEN_FIELD_URI_REF));
+        let uri = resolve_token_uri(
+            &storage,
+            token_id,
+            read_string_field(&storage, &token_field_key(token_id, TOKEN_FIELD_URI_REF)),
+        );
         let properties_json =
             read_string_field(&storage, &token_field_key(token_id, TOKEN_FIELD_PROPERTIES_REF));
 
@@ -365,22 +583,13 @@ impl MultiTenantNftPlatformRust {
             return NeoString::from_str("[]");
         }
 
-        let collection_id = read_i64(&storage, &token_field_key(token_id, TOKEN_FIELD_COLLECTION_ID));
-        if collection_id <= 0 {
-            return NeoString::from_str("[]");
-        }
-
-        let royalty_bps = read_i64(&storage, &collection_field_key(collection_id, FIELD_ROYALTY_BPS));
-        if royalty_bps <= 0 {
-            return NeoString::from_str("[]");
+        let mut entries = Vec::new();
+        for (account_id, bps) in royalty_recipients(&storage, token_id) {
+            let account_hex = hex_encode(account_hash160(&storage, account_id).as_slice());
+            entries.push(format!("{{\"address\":\"{}\",\"value\":{}}}", account_hex, bps));
         }
 
-        let owner = read_i64(&storage, &collection_field_key(collection_id, FIELD_OWNER));
-        let owner_hex = hex_encode(account_hash160(&storage, owner).as_slice());
-        NeoString::from_str(&format!(
-            "[{{\"address\":\"{}\",\"value\":{}}}]",
-            owner_hex, royalty_bps
-        ))
+        NeoString::from_str(&format!("[{}]", entries.join(",")))
     }
 
     #[neo_method(
@@ -403,23 +612,17 @@ impl MultiTenantNftPlatformRust {
             return NeoValue::Array(result);
         }
 
-        let collection_id = read_i64(&storage, &token_field_key(token_id, TOKEN_FIELD_COLLECTION_ID));
-        if collection_id <= 0 {
+        let Some(payouts) = royalty_payouts(&storage, token_id, sale_price) else {
             return NeoValue::Array(result);
-        }
+        };
 
-        let royalty_bps = read_i64(&storage, &collection_field_key(collection_id, FIELD_ROYALTY_BPS));
-        if royalty_bps <= 0 {
-            return NeoValue::Array(result);
+        for (account_id, amount) in payouts {
+            let mut entry = NeoArray::new();
+            entry.push(hash160_value_from_account_id(&storage, Some(account_id)));
+            entry.push(NeoValue::Integer(NeoInteger::new(amount)));
+            result.push(NeoValue::Array(entry));
         }
 
-        let Some(product) = sale_price.checked_mul(royalty_bps) else {
-            return NeoValue::Array(result);
-        };
-
-        let owner = read_i64(&storage, &collection_field_key(collection_id, FIELD_OWNER));
-        result.push(hash160_value_from_account_id(&storage, Some(owner)));
-        result.push(NeoValue::Integer(NeoInteger::new(product / 10000)));
         NeoValue::Array(result)
     }
 
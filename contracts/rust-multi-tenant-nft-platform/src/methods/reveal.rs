@@ -0,0 +1,335 @@
+use neo_devpack::prelude::*;
+
+use crate::constants::*;
+use crate::helpers::*;
+use crate::keys::*;
+use crate::storage_helpers::*;
+use crate::MultiTenantNftPlatformRust;
+
+fn bytes_arg(value_ref: i64) -> Option<NeoByteString> {
+    match neo_devpack::abi::resolve_value(value_ref) {
+        Some(NeoValue::ByteString(bytes)) => Some(bytes),
+        Some(NeoValue::String(text)) => Some(NeoByteString::from_slice(text.as_str().as_bytes())),
+        _ => None,
+    }
+}
+
+// The offset is derived from sha256(seed || provenanceHash || entropy). The entropy comes from the runtime at
+// reveal time and is stored, so the creator cannot grind the seed before committing, yet anyone can recheck it.
+fn derive_reveal_offset<D>(
+    seed: &[u8],
+    provenance_hash: &[u8],
+    entropy: &[u8],
+    supply: i64,
+    digest_of: D,
+) -> Option<i64>
+where
+    D: Fn(&[u8]) -> Option<NeoByteString>,
+{
+    if supply <= 0 {
+        return None;
+    }
+
+    let mut preimage = seed.to_vec();
+    preimage.extend_from_slice(provenance_hash);
+    preimage.extend_from_slice(entropy);
+    let digest = digest_of(&preimage)?;
+    if digest.len() < 8 {
+        return None;
+    }
+
+    let mut word = [0u8; 8];
+    word.copy_from_slice(&digest.as_slice()[..8]);
+    Some((u64::from_le_bytes(word) % supply as u64) as i64)
+}
+
+fn emit_reveal_configured(collection_id: i64, provenance_hash: &NeoByteString) {
+    let mut state = NeoArray::new();
+    state.push(token_id_value(collection_id));
+    state.push(NeoValue::ByteString(provenance_hash.clone()));
+
+    let label = NeoString::from_str("RevealConfigured");
+    let _ = NeoRuntime::notify(&label, &state);
+}
+
+fn emit_collection_revealed(collection_id: i64, base_uri: &NeoString, offset: i64, revealed_at: i64) {
+    let mut state = NeoArray::new();
+    state.push(token_id_value(collection_id));
+    state.push(NeoValue::String(base_uri.clone()));
+    state.push(NeoValue::Integer(NeoInteger::new(offset)));
+    state.push(NeoValue::Integer(NeoInteger::new(revealed_at)));
+
+    let label = NeoString::from_str("CollectionRevealed");
+    let _ = NeoRuntime::notify(&label, &state);
+}
+
+#[neo_contract]
+impl MultiTenantNftPlatformRust {
+    #[neo_method(
+        name = "configureReveal",
+        param_types = ["Hash160", "ByteArray", "String", "ByteArray"]
+    )]
+    pub fn configure_reveal(
+        creator: i64,
+        collection_id: i64,
+        placeholder_uri_ref: i64,
+        provenance_hash_ref: i64,
+    ) -> bool {
+        if creator <= 0 || collection_id <= 0 {
+            return false;
+        }
+
+        let placeholder_uri = string_ref(placeholder_uri_ref);
+        if placeholder_uri.as_str().is_empty() || placeholder_uri.len() > 512 {
+            return false;
+        }
+
+        let Some(NeoValue::ByteString(provenance_hash)) = neo_devpack::abi::resolve_value(provenance_hash_ref) else {
+            return false;
+        };
+        if provenance_hash.len() != 32 {
+            return false;
+        }
+
+        let Some(storage) = storage_context() else {
+            return false;
+        };
+
+        let creator_id = canonical_account_id(&storage, creator);
+        if creator_id <= 0 || !check_witness_for_account_ref(&storage, creator) {
+            return false;
+        }
+
+        if !collection_exists(&storage, collection_id) {
+            return false;
+        }
+
+        let owner = read_i64(&storage, &collection_field_key(collection_id, FIELD_OWNER));
+        if owner != creator_id || is_collection_metadata_frozen(&storage, collection_id) {
+            return false;
+        }
+
+        // The commitment only means something if it is published before any token exists.
+        if read_i64(&storage, &collection_field_key(collection_id, FIELD_MINTED)) > 0
+            || read_bool(&storage, &reveal_config_key(collection_id, REVEAL_FIELD_REVEALED))
+        {
+            return false;
+        }
+
+        if !write_bool(&storage, &reveal_config_key(collection_id, REVEAL_FIELD_ENABLED), true)
+            || !write_string_field(
+                &storage,
+                &reveal_config_key(collection_id, REVEAL_FIELD_PLACEHOLDER_URI),
+                &placeholder_uri,
+            )
+            || !write_bytes(
+                &storage,
+                &reveal_config_key(collection_id, REVEAL_FIELD_PROVENANCE_HASH),
+                &provenance_hash,
+            )
+        {
+            return false;
+        }
+
+        emit_reveal_configured(collection_id, &provenance_hash);
+        true
+    }
+
+    #[neo_method(name = "reveal", param_types = ["Hash160", "ByteArray", "String", "ByteArray"])]
+    pub fn reveal(creator: i64, collection_id: i64, base_uri_ref: i64, seed_ref: i64) -> bool {
+        if creator <= 0 || collection_id <= 0 {
+            return false;
+        }
+
+        let base_uri = string_ref(base_uri_ref);
+        if base_uri.as_str().is_empty() || base_uri.len() > 512 {
+            return false;
+        }
+
+        let Some(seed) = bytes_arg(seed_ref) else {
+            return false;
+        };
+        if seed.as_slice().is_empty() || seed.len() > 256 {
+            return false;
+        }
+
+        let Some(storage) = storage_context() else {
+            return false;
+        };
+
+        let creator_id = canonical_account_id(&storage, creator);
+        if creator_id <= 0 || !check_witness_for_account_ref(&storage, creator) {
+            return false;
+        }
+
+        if !collection_exists(&storage, collection_id) || !is_reveal_pending(&storage, collection_id) {
+            return false;
+        }
+
+        // Revealing rewrites every token URI, which a metadata freeze has already promised will not happen.
+        let owner = read_i64(&storage, &collection_field_key(collection_id, FIELD_OWNER));
+        if owner != creator_id || is_collection_metadata_frozen(&storage, collection_id) {
+            return false;
+        }
+
+        let Some(provenance_hash) = read_bytes(
+            &storage,
+            &reveal_config_key(collection_id, REVEAL_FIELD_PROVENANCE_HASH),
+        ) else {
+            return false;
+        };
+
+        match reveal_commitment(&base_uri, seed.as_slice()) {
+            Some(digest) if digest.as_slice() == provenance_hash.as_slice() => {}
+            _ => return false,
+        }
+
+        // Unbounded collections shuffle only the serials minted so far.
+        let max_supply = read_i64(&storage, &collection_field_key(collection_id, FIELD_MAX_SUPPLY));
+        let supply = if max_supply > 0 {
+            max_supply
+        } else {
+            read_i64(&storage, &collection_field_key(collection_id, FIELD_MINTED))
+        };
+        let Some(entropy) = runtime_entropy() else {
+            return false;
+        };
+        let Some(offset) =
+            derive_reveal_offset(seed.as_slice(), provenance_hash.as_slice(), entropy.as_slice(), supply, sha256)
+        else {
+            return false;
+        };
+
+        let revealed_at = now();
+        if !write_bool(&storage, &reveal_config_key(collection_id, REVEAL_FIELD_REVEALED), true)
+            || !write_string_field(&storage, &reveal_config_key(collection_id, REVEAL_FIELD_BASE_URI), &base_uri)
+            || !write_bytes(&storage, &reveal_config_key(collection_id, REVEAL_FIELD_SEED), &seed)
+            || !write_bytes(&storage, &reveal_config_key(collection_id, REVEAL_FIELD_ENTROPY), &entropy)
+            || !write_i64(&storage, &reveal_config_key(collection_id, REVEAL_FIELD_OFFSET), offset)
+            || !write_i64(&storage, &reveal_config_key(collection_id, REVEAL_FIELD_SUPPLY), supply)
+            || !write_i64(&storage, &reveal_config_key(collection_id, REVEAL_FIELD_REVEALED_AT), revealed_at)
+        {
+            return false;
+        }
+
+        emit_collection_revealed(collection_id, &base_uri, offset, revealed_at);
+        true
+    }
+
+    #[neo_method(
+        name = "verifyReveal",
+        safe,
+        param_types = ["ByteArray", "String", "ByteArray"]
+    )]
+    pub fn verify_reveal(collection_id: i64, base_uri_ref: i64, seed_ref: i64) -> bool {
+        let Some(seed) = bytes_arg(seed_ref) else {
+            return false;
+        };
+
+        let Some(storage) = storage_context() else {
+            return false;
+        };
+
+        let Some(provenance_hash) = read_bytes(
+            &storage,
+            &reveal_config_key(collection_id, REVEAL_FIELD_PROVENANCE_HASH),
+        ) else {
+            return false;
+        };
+        if provenance_hash.len() != 32 {
+            return false;
+        }
+
+        match reveal_commitment(&string_ref(base_uri_ref), seed.as_slice()) {
+            Some(digest) => digest.as_slice() == provenance_hash.as_slice(),
+            None => false,
+        }
+    }
+
+    #[neo_method(
+        name = "getRevealConfig",
+        safe,
+        param_types = ["ByteArray"],
+        return_type = "Array"
+    )]
+    pub fn get_reveal_config(collection_id: i64) -> i64 {
+        let Some(storage) = storage_context() else {
+            return 0;
+        };
+
+        if !collection_exists(&storage, collection_id) {
+            return 0;
+        }
+
+        let empty = NeoByteString::from_slice(&[]);
+        let provenance_hash = read_bytes(&storage, &reveal_config_key(collection_id, REVEAL_FIELD_PROVENANCE_HASH))
+            .unwrap_or_else(|| empty.clone());
+        let seed = read_bytes(&storage, &reveal_config_key(collection_id, REVEAL_FIELD_SEED))
+            .unwrap_or_else(|| empty.clone());
+        let entropy = read_bytes(&storage, &reveal_config_key(collection_id, REVEAL_FIELD_ENTROPY)).unwrap_or(empty);
+
+        let mut result = NeoArray::new();
+        result.push(NeoValue::Boolean(NeoBoolean::new(read_bool(
+            &storage,
+            &reveal_config_key(collection_id, REVEAL_FIELD_ENABLED),
+        ))));
+        result.push(NeoValue::Boolean(NeoBoolean::new(read_bool(
+            &storage,
+            &reveal_config_key(collection_id, REVEAL_FIELD_REVEALED),
+        ))));
+        result.push(NeoValue::String(read_string_field(
+            &storage,
+            &reveal_config_key(collection_id, REVEAL_FIELD_PLACEHOLDER_URI),
+        )));
+        result.push(NeoValue::ByteString(provenance_hash));
+        result.push(NeoValue::String(read_string_field(
+            &storage,
+            &reveal_config_key(collection_id, REVEAL_FIELD_BASE_URI),
+        )));
+        result.push(NeoValue::ByteString(seed));
+        result.push(NeoValue::Integer(NeoInteger::new(read_i64(
+            &storage,
+            &reveal_config_key(collection_id, REVEAL_FIELD_OFFSET),
+        ))));
+        result.push(NeoValue::Integer(NeoInteger::new(read_i64(
+            &storage,
+            &reveal_config_key(collection_id, REVEAL_FIELD_SUPPLY),
+        ))));
+        result.push(NeoValue::Integer(NeoInteger::new(read_i64(
+            &storage,
+            &reveal_config_key(collection_id, REVEAL_FIELD_REVEALED_AT),
+        ))));
+        result.push(NeoValue::ByteString(entropy));
+        to_iterator_handle(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::derive_reveal_offset;
+    use neo_devpack::prelude::NeoByteString;
+
+    // Stand-in for CryptoLib.sha256: FNV-1a over the preimage, enough to show which inputs reach the offset.
+    fn test_digest(preimage: &[u8]) -> Option<NeoByteString> {
+        let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+        for byte in preimage {
+            hash ^= u64::from(*byte);
+            hash = hash.wrapping_mul(0x1000_0000_01b3);
+        }
+        Some(NeoByteString::from_slice(&hash.to_le_bytes()))
+    }
+
+    #[test]
+    fn entropy_changes_the_offset() {
+        let provenance = [0u8; 32];
+        let first = derive_reveal_offset(b"seed", &provenance, &[0x01], 10_000, test_digest);
+        let second = derive_reveal_offset(b"seed", &provenance, &[0x02], 10_000, test_digest);
+        assert_eq!(first, Some(1_687));
+        assert_eq!(second, Some(9_898));
+    }
+
+    #[test]
+    fn empty_supply_has_no_offset() {
+        assert_eq!(derive_reveal_offset(b"seed", &[0u8; 32], &[0x01], 0, test_digest), None);
+    }
+}
@@ -3,6 +3,7 @@ use neo_devpack::prelude::*;
 use crate::constants::*;
 use crate::helpers::*;
 use crate::keys::*;
+use crate::methods::market::clear_token_listing;
 use crate::storage_helpers::*;
 use crate::MultiTenantNftPlatformRust;
 
@@ -96,6 +97,22 @@ pub(crate) fn mint_token_for_account(
     token_uri_ref: i64,
     properties_ref: i64,
     token_class: i64,
+) -> i64 {
+    let token_id = mint_token(storage, collection_id, to_id, token_uri_ref, properties_ref, token_class);
+    if token_id > 0 {
+        emit_collection_upserted(storage, collection_id);
+    }
+    token_id
+}
+
+// Mints without announcing the collection so batch callers can emit a single CollectionUpserted at the end.
+fn mint_token(
+    storage: &NeoStorageContext,
+    collection_id: i64,
+    to_id: i64,
+    token_uri_ref: i64,
+    properties_ref: i64,
+    token_class: i64,
 ) -> i64 {
     if collection_id <= 0 || to_id <= 0 {
         return 0;
@@ -105,7 +122,7 @@ pub(crate) fn mint_token_for_account(
         return 0;
     }
 
-    if read_bool(storage, &collection_field_key(collection_id, FIELD_PAUSED)) {
+    if !is_collection_active(storage, collection_id) {
         return 0;
     }
 
@@ -144,6 +161,14 @@ pub(crate) fn mint_token_for_account(
         effective_properties = NeoString::from_str(&default_properties);
     }
 
+    // Collections with a reveal mint every token onto the committed placeholder; once revealed,
+    // resolve_token_uri serves later serials from the revealed base URI.
+    let reveal_pending = read_bool(storage, &reveal_config_key(collection_id, REVEAL_FIELD_ENABLED));
+    if reveal_pending {
+        effective_token_uri =
+            read_string_field(storage, &reveal_config_key(collection_id, REVEAL_FIELD_PLACEHOLDER_URI));
+    }
+
     if effective_token_uri.len() > 512 || effective_properties.len() > 4096 {
         return 0;
     }
@@ -168,6 +193,7 @@ pub(crate) fn mint_token_for_account(
         || !write_bool(storage, &token_field_key(token_id, TOKEN_FIELD_BURNED), false)
         || !write_i64(storage, &token_field_key(token_id, TOKEN_FIELD_MINTED_AT), now())
         || !write_i64(storage, &token_field_key(token_id, TOKEN_FIELD_CLASS), token_class)
+        || !write_bool(storage, &token_field_key(token_id, TOKEN_FIELD_REVEAL_PENDING), reveal_pending)
         || !write_i64(
             storage,
             &collection_field_key(collection_id, FIELD_MINTED),
@@ -178,7 +204,10 @@ pub(crate) fn mint_token_for_account(
     }
 
     let balance = load_balance(storage, to_id);
-    if !save_balance(storage, to_id, balance + 1) {
+    if !save_balance(storage, to_id, balance + 1)
+        || !add_owner_token_index(storage, to_id, token_id)
+        || !add_token_find_entries(storage, token_id)
+    {
         return 0;
     }
 
@@ -191,15 +220,17 @@ pub(crate) fn mint_token_for_account(
         return 0;
     }
 
-    let global_counter = read_i64(storage, KEY_GLOBAL_TOKEN_COUNTER) + 1;
-    if !write_i64(storage, KEY_GLOBAL_TOKEN_COUNTER, global_counter)
-        || !write_i64(storage, &global_token_key(global_counter), token_id)
-        || !write_i64(storage, &collection_token_key(collection_id, serial), token_id)
-    {
-        return 0;
+    // The mint-order lists only feed the schema migration; afterwards the find entries replace them.
+    if !is_owner_token_index_ready(storage) {
+        let global_counter = read_i64(storage, KEY_GLOBAL_TOKEN_COUNTER) + 1;
+        if !write_i64(storage, KEY_GLOBAL_TOKEN_COUNTER, global_counter)
+            || !write_i64(storage, &global_token_key(global_counter), token_id)
+            || !write_i64(storage, &collection_token_key(collection_id, serial), token_id)
+        {
+            return 0;
+        }
     }
 
-    emit_collection_upserted(storage, collection_id);
     emit_token_upserted(storage, token_id);
     emit_transfer(storage, None, Some(to_id), token_id);
     if !call_nep11_receiver(storage, None, to_id, token_id, NeoValue::Null) {
@@ -208,6 +239,166 @@ pub(crate) fn mint_token_for_account(
     token_id
 }
 
+fn emit_approval(storage: &NeoStorageContext, owner: i64, approved: i64, token_id: i64) {
+    let mut state = NeoArray::new();
+    state.push(hash160_value_from_account_id(storage, Some(owner)));
+    state.push(hash160_value_from_account_id(storage, Some(approved)));
+    state.push(token_id_value(token_id));
+
+    let label = NeoString::from_str("Approval");
+    let _ = NeoRuntime::notify(&label, &state);
+}
+
+fn emit_approval_for_all(storage: &NeoStorageContext, owner: i64, operator: i64, approved: bool) {
+    let mut state = NeoArray::new();
+    state.push(hash160_value_from_account_id(storage, Some(owner)));
+    state.push(hash160_value_from_account_id(storage, Some(operator)));
+    state.push(NeoValue::Boolean(NeoBoolean::new(approved)));
+
+    let label = NeoString::from_str("ApprovalForAll");
+    let _ = NeoRuntime::notify(&label, &state);
+}
+
+fn is_approved_spender(storage: &NeoStorageContext, token_id: i64, spender_id: i64) -> bool {
+    let owner = read_i64(storage, &token_field_key(token_id, TOKEN_FIELD_OWNER));
+    spender_id == owner
+        || read_i64(storage, &token_field_key(token_id, TOKEN_FIELD_APPROVED)) == spender_id
+        || read_bool(storage, &approval_for_all_key(owner, spender_id))
+}
+
+// Moves a live token to `to_id` once the caller has authorized the move; approvals never survive a change of owner.
+pub(crate) fn transfer_token(storage: &NeoStorageContext, to_id: i64, token_id: i64, data: NeoValue) -> bool {
+    if !is_token_tradable(storage, token_id) {
+        return false;
+    }
+
+    let collection_id = read_i64(storage, &token_field_key(token_id, TOKEN_FIELD_COLLECTION_ID));
+    let token_class = read_i64(storage, &token_field_key(token_id, TOKEN_FIELD_CLASS));
+    let from = read_i64(storage, &token_field_key(token_id, TOKEN_FIELD_OWNER));
+    if from == to_id {
+        return true;
+    }
+
+    let from_balance = load_balance(storage, from);
+    if from_balance > 0 && !save_balance(storage, from, from_balance - 1) {
+        return false;
+    }
+
+    let to_balance = load_balance(storage, to_id);
+    if !save_balance(storage, to_id, to_balance + 1)
+        || !write_i64(storage, &token_field_key(token_id, TOKEN_FIELD_OWNER), to_id)
+        || !write_i64(storage, &token_field_key(token_id, TOKEN_FIELD_APPROVED), 0)
+        || !clear_token_listing(storage, token_id)
+        || !remove_owner_token_index(storage, from, token_id)
+        || !add_owner_token_index(storage, to_id, token_id)
+    {
+        return false;
+    }
+
+    if token_class == TOKEN_CLASS_MEMBERSHIP
+        && (!subtract_membership_balance(storage, collection_id, from, 1)
+            || !add_membership_balance(storage, collection_id, to_id, 1))
+    {
+        return false;
+    }
+
+    emit_token_upserted(storage, token_id);
+    emit_transfer(storage, Some(from), Some(to_id), token_id);
+    if !call_nep11_receiver(storage, Some(from), to_id, token_id, data) {
+        panic!("Invalid NEP-11 receiver");
+    }
+    true
+}
+
+// The public `mint` keeps its membership default; `mintStandard` and `mintWithClass` pick the class explicitly.
+fn operator_mint(
+    operator: i64,
+    collection_id: i64,
+    to: i64,
+    token_uri_ref: i64,
+    properties_ref: i64,
+    token_class: i64,
+) -> i64 {
+    if operator <= 0 || collection_id <= 0 || to <= 0 {
+        return 0;
+    }
+
+    let Some(storage) = storage_context() else {
+        return 0;
+    };
+
+    let operator_id = canonical_account_id(&storage, operator);
+    let to_id = canonical_account_id(&storage, to);
+    if operator_id <= 0 || to_id <= 0 || !check_witness_for_account_ref(&storage, operator) {
+        return 0;
+    }
+
+    if !collection_exists(&storage, collection_id)
+        || !can_manage_collection(&storage, collection_id, operator_id, OPERATOR_PERMISSION_MINT)
+    {
+        return 0;
+    }
+
+    mint_token_for_account(
+        &storage,
+        collection_id,
+        to_id,
+        token_uri_ref,
+        properties_ref,
+        token_class,
+    )
+}
+
+fn emit_token_metadata_frozen(token_id: i64, collection_id: i64, frozen_at: i64) {
+    let mut state = NeoArray::new();
+    state.push(token_id_value(token_id));
+    state.push(token_id_value(collection_id));
+    state.push(NeoValue::Integer(NeoInteger::new(frozen_at)));
+
+    let label = NeoString::from_str("TokenMetadataFrozen");
+    let _ = NeoRuntime::notify(&label, &state);
+}
+
+fn emit_token_royalty_updated(storage: &NeoStorageContext, token_id: i64, recipient: i64, bps: i64, overridden: bool) {
+    let mut state = NeoArray::new();
+    state.push(token_id_value(token_id));
+    state.push(hash160_value_from_account_id(storage, Some(recipient)));
+    state.push(NeoValue::Integer(NeoInteger::new(bps)));
+    state.push(NeoValue::Boolean(NeoBoolean::new(overridden)));
+
+    let label = NeoString::from_str("TokenRoyaltyUpdated");
+    let _ = NeoRuntime::notify(&label, &state);
+}
+
+// Overrides redirect sale proceeds, so they need their own grant rather than riding on the metadata permission.
+fn can_override_token_royalty(storage: &NeoStorageContext, token_id: i64, operator_id: i64) -> bool {
+    if !token_exists(storage, token_id) || read_bool(storage, &token_field_key(token_id, TOKEN_FIELD_BURNED)) {
+        return false;
+    }
+
+    let collection_id = read_i64(storage, &token_field_key(token_id, TOKEN_FIELD_COLLECTION_ID));
+    can_manage_collection(storage, collection_id, operator_id, OPERATOR_PERMISSION_SET_ROYALTY)
+        && !is_token_metadata_frozen(storage, token_id)
+}
+
+fn store_token_royalty(
+    storage: &NeoStorageContext,
+    token_id: i64,
+    recipient_id: i64,
+    bps: i64,
+    overridden: bool,
+) -> bool {
+    if !write_bool(storage, &token_field_key(token_id, TOKEN_FIELD_ROYALTY_OVERRIDE), overridden)
+        || !write_i64(storage, &token_field_key(token_id, TOKEN_FIELD_ROYALTY_RECIPIENT), recipient_id)
+        || !write_i64(storage, &token_field_key(token_id, TOKEN_FIELD_ROYALTY_BPS), bps)
+    {
+        return false;
+    }
+
+    emit_token_royalty_updated(storage, token_id, recipient_id, bps, overridden);
+    true
+}
+
 #[neo_contract]
 impl MultiTenantNftPlatformRust {
     #[neo_method(
@@ -215,7 +406,64 @@ impl MultiTenantNftPlatformRust {
         param_types = ["Hash160", "ByteArray", "Hash160", "Integer", "Integer"]
     )]
     pub fn mint(operator: i64, collection_id: i64, to: i64, token_uri_ref: i64, properties_ref: i64) -> i64 {
-        if operator <= 0 || collection_id <= 0 || to <= 0 {
+        operator_mint(
+            operator,
+            collection_id,
+            to,
+            token_uri_ref,
+            properties_ref,
+            TOKEN_CLASS_MEMBERSHIP,
+        )
+    }
+
+    #[neo_method(
+        name = "mintStandard",
+        param_types = ["Hash160", "ByteArray", "Hash160", "Integer", "Integer"]
+    )]
+    pub fn mint_standard(operator: i64, collection_id: i64, to: i64, token_uri_ref: i64, properties_ref: i64) -> i64 {
+        operator_mint(
+            operator,
+            collection_id,
+            to,
+            token_uri_ref,
+            properties_ref,
+            TOKEN_CLASS_STANDARD,
+        )
+    }
+
+    #[neo_method(
+        name = "mintWithClass",
+        param_types = ["Hash160", "ByteArray", "Hash160", "Integer", "Integer", "Integer"]
+    )]
+    pub fn mint_with_class(
+        operator: i64,
+        collection_id: i64,
+        to: i64,
+        token_uri_ref: i64,
+        properties_ref: i64,
+        token_class: i64,
+    ) -> i64 {
+        operator_mint(operator, collection_id, to, token_uri_ref, properties_ref, token_class)
+    }
+
+    #[neo_method(
+        name = "mintBatch",
+        param_types = ["Hash160", "ByteArray", "Any", "Any", "Any", "Integer"],
+        return_type = "Array"
+    )]
+    pub fn mint_batch(
+        operator: i64,
+        collection_id: i64,
+        recipients_ref: i64,
+        token_uris_ref: i64,
+        properties_ref: i64,
+        token_class: i64,
+    ) -> i64 {
+        if operator <= 0 || collection_id <= 0 {
+            return 0;
+        }
+
+        if !(TOKEN_CLASS_STANDARD..=TOKEN_CLASS_CHECKIN_PROOF).contains(&token_class) {
             return 0;
         }
 
@@ -224,24 +472,73 @@ impl MultiTenantNftPlatformRust {
         };
 
         let operator_id = canonical_account_id(&storage, operator);
-        let to_id = canonical_account_id(&storage, to);
-        if operator_id <= 0 || to_id <= 0 || !check_witness_for_account_ref(&storage, operator) {
+        if operator_id <= 0 || !check_witness_for_account_ref(&storage, operator) {
             return 0;
         }
 
-        if !collection_exists(&storage, collection_id) || !can_manage_collection(&storage, collection_id, operator_id)
+        if !collection_exists(&storage, collection_id)
+            || !can_manage_collection(&storage, collection_id, operator_id, OPERATOR_PERMISSION_MINT)
         {
             return 0;
         }
 
-        mint_token_for_account(
-            &storage,
-            collection_id,
-            to_id,
-            token_uri_ref,
-            properties_ref,
-            TOKEN_CLASS_MEMBERSHIP,
-        )
+        let Some(NeoValue::Array(recipients)) = neo_devpack::abi::resolve_value(recipients_ref) else {
+            return 0;
+        };
+        let Some(NeoValue::Array(token_uris)) = neo_devpack::abi::resolve_value(token_uris_ref) else {
+            return 0;
+        };
+        let Some(NeoValue::Array(properties)) = neo_devpack::abi::resolve_value(properties_ref) else {
+            return 0;
+        };
+
+        // Empty URI or properties lists fall back to the per-token defaults.
+        let count = recipients.len();
+        let uri_count = token_uris.len();
+        let properties_count = properties.len();
+        if count == 0
+            || count > MAX_BATCH_MINT_SIZE
+            || (uri_count != 0 && uri_count != count)
+            || (properties_count != 0 && properties_count != count)
+        {
+            return 0;
+        }
+
+        let mut token_ids = NeoArray::new();
+        let mut index = 0usize;
+        while index < count {
+            let to_id = recipients
+                .get(index)
+                .map(|value| canonical_account_id(&storage, neo_devpack::abi::i64_from_value(value)))
+                .unwrap_or(0);
+            let token_uri_ref = token_uris
+                .get(index)
+                .map(|value| neo_devpack::abi::i64_from_value(value))
+                .unwrap_or(0);
+            let token_properties_ref = properties
+                .get(index)
+                .map(|value| neo_devpack::abi::i64_from_value(value))
+                .unwrap_or(0);
+
+            let token_id = mint_token(
+                &storage,
+                collection_id,
+                to_id,
+                token_uri_ref,
+                token_properties_ref,
+                token_class,
+            );
+            if token_id <= 0 {
+                // Abort the whole invocation so a partial airdrop is never persisted.
+                panic!("Batch mint failed");
+            }
+
+            token_ids.push(token_id_value(token_id));
+            index += 1;
+        }
+
+        emit_collection_upserted(&storage, collection_id);
+        to_iterator_handle(token_ids)
     }
 
     #[neo_method(name = "burn", param_types = ["Hash160", "ByteArray"])]
@@ -263,7 +560,7 @@ impl MultiTenantNftPlatformRust {
             return false;
         }
 
-        if read_bool(&storage, &token_field_key(token_id, TOKEN_FIELD_BURNED)) {
+        if read_bool(&storage, &token_field_key(token_id, TOKEN_FIELD_BURNED)) || is_token_in_auction(&storage, token_id) {
             return false;
         }
 
@@ -271,12 +568,18 @@ impl MultiTenantNftPlatformRust {
         let token_owner = read_i64(&storage, &token_field_key(token_id, TOKEN_FIELD_OWNER));
         let token_class = read_i64(&storage, &token_field_key(token_id, TOKEN_FIELD_CLASS));
 
-        let authorized = operator_id == token_owner || can_manage_collection(&storage, collection_id, operator_id);
+        let authorized = operator_id == token_owner
+            || can_manage_collection(&storage, collection_id, operator_id, OPERATOR_PERMISSION_BURN);
         if !authorized {
             return false;
         }
 
-        if !write_bool(&storage, &token_field_key(token_id, TOKEN_FIELD_BURNED), true) {
+        if !write_bool(&storage, &token_field_key(token_id, TOKEN_FIELD_BURNED), true)
+            || !write_i64(&storage, &token_field_key(token_id, TOKEN_FIELD_APPROVED), 0)
+            || !clear_token_listing(&storage, token_id)
+            || !remove_owner_token_index(&storage, token_owner, token_id)
+            || !delete_key(&storage, &find_token_key(token_id))
+        {
             return false;
         }
 
@@ -301,6 +604,167 @@ impl MultiTenantNftPlatformRust {
         true
     }
 
+    #[neo_method(
+        name = "updateTokenMetadata",
+        param_types = ["Hash160", "ByteArray", "Integer", "Integer"]
+    )]
+    pub fn update_token_metadata(operator: i64, token_id: i64, token_uri_ref: i64, properties_ref: i64) -> bool {
+        if operator <= 0 || token_id <= 0 {
+            return false;
+        }
+
+        let Some(storage) = storage_context() else {
+            return false;
+        };
+
+        let operator_id = canonical_account_id(&storage, operator);
+        if operator_id <= 0 || !check_witness_for_account_ref(&storage, operator) {
+            return false;
+        }
+
+        if !token_exists(&storage, token_id) || read_bool(&storage, &token_field_key(token_id, TOKEN_FIELD_BURNED)) {
+            return false;
+        }
+
+        let collection_id = read_i64(&storage, &token_field_key(token_id, TOKEN_FIELD_COLLECTION_ID));
+        if !can_manage_collection(&storage, collection_id, operator_id, OPERATOR_PERMISSION_UPDATE_METADATA) {
+            return false;
+        }
+
+        // Placeholder tokens are part of the provenance commitment until the collection is revealed.
+        if is_token_metadata_frozen(&storage, token_id) || is_reveal_pending(&storage, collection_id) {
+            return false;
+        }
+
+        // Empty values keep the stored field, so callers can update the URI and properties independently.
+        let token_uri = if token_uri_ref > 0 {
+            string_ref(token_uri_ref)
+        } else {
+            NeoString::from_str("")
+        };
+        let properties = if properties_ref > 0 {
+            string_ref(properties_ref)
+        } else {
+            NeoString::from_str("")
+        };
+        if token_uri.len() > 512 || properties.len() > 4096 {
+            return false;
+        }
+
+        let update_uri = !token_uri.as_str().is_empty();
+        let update_properties = !properties.as_str().is_empty();
+        if !update_uri && !update_properties {
+            return false;
+        }
+
+        // An explicit URI replaces the placeholder, so the token no longer follows the collection reveal.
+        if update_uri
+            && (!write_string_field(&storage, &token_field_key(token_id, TOKEN_FIELD_URI_REF), &token_uri)
+                || !write_bool(&storage, &token_field_key(token_id, TOKEN_FIELD_REVEAL_PENDING), false))
+        {
+            return false;
+        }
+
+        if update_properties
+            && !write_string_field(&storage, &token_field_key(token_id, TOKEN_FIELD_PROPERTIES_REF), &properties)
+        {
+            return false;
+        }
+
+        let version = read_i64(&storage, &token_field_key(token_id, TOKEN_FIELD_METADATA_VERSION)) + 1;
+        if !write_i64(&storage, &token_field_key(token_id, TOKEN_FIELD_METADATA_VERSION), version) {
+            return false;
+        }
+
+        emit_token_upserted(&storage, token_id);
+        true
+    }
+
+    #[neo_method(name = "setTokenRoyalty", param_types = ["Hash160", "ByteArray", "Hash160", "Integer"])]
+    pub fn set_token_royalty(operator: i64, token_id: i64, recipient: i64, royalty_bps: i64) -> bool {
+        if operator <= 0 || token_id <= 0 || !(0..=10000).contains(&royalty_bps) {
+            return false;
+        }
+
+        let Some(storage) = storage_context() else {
+            return false;
+        };
+
+        let operator_id = canonical_account_id(&storage, operator);
+        if operator_id <= 0 || !check_witness_for_account_ref(&storage, operator) {
+            return false;
+        }
+
+        if !can_override_token_royalty(&storage, token_id, operator_id) {
+            return false;
+        }
+
+        let recipient_id = canonical_account_id(&storage, recipient);
+        if recipient_id <= 0 {
+            return false;
+        }
+
+        store_token_royalty(&storage, token_id, recipient_id, royalty_bps, true)
+    }
+
+    #[neo_method(name = "clearTokenRoyalty", param_types = ["Hash160", "ByteArray"])]
+    pub fn clear_token_royalty(operator: i64, token_id: i64) -> bool {
+        if operator <= 0 || token_id <= 0 {
+            return false;
+        }
+
+        let Some(storage) = storage_context() else {
+            return false;
+        };
+
+        let operator_id = canonical_account_id(&storage, operator);
+        if operator_id <= 0 || !check_witness_for_account_ref(&storage, operator) {
+            return false;
+        }
+
+        if !can_override_token_royalty(&storage, token_id, operator_id) {
+            return false;
+        }
+
+        store_token_royalty(&storage, token_id, 0, 0, false)
+    }
+
+    #[neo_method(name = "freezeTokenMetadata", param_types = ["Hash160", "ByteArray"])]
+    pub fn freeze_token_metadata(operator: i64, token_id: i64) -> bool {
+        if operator <= 0 || token_id <= 0 {
+            return false;
+        }
+
+        let Some(storage) = storage_context() else {
+            return false;
+        };
+
+        let operator_id = canonical_account_id(&storage, operator);
+        if operator_id <= 0 || !check_witness_for_account_ref(&storage, operator) {
+            return false;
+        }
+
+        if !token_exists(&storage, token_id) || read_bool(&storage, &token_field_key(token_id, TOKEN_FIELD_BURNED)) {
+            return false;
+        }
+
+        let collection_id = read_i64(&storage, &token_field_key(token_id, TOKEN_FIELD_COLLECTION_ID));
+        if !can_manage_collection(&storage, collection_id, operator_id, OPERATOR_PERMISSION_UPDATE_METADATA) {
+            return false;
+        }
+
+        if read_bool(&storage, &token_field_key(token_id, TOKEN_FIELD_METADATA_FROZEN)) {
+            return false;
+        }
+
+        if !write_bool(&storage, &token_field_key(token_id, TOKEN_FIELD_METADATA_FROZEN), true) {
+            return false;
+        }
+
+        emit_token_metadata_frozen(token_id, collection_id, now());
+        true
+    }
+
     #[neo_method(name = "transfer", param_types = ["Hash160", "ByteArray", "Any"])]
     pub fn transfer(to: i64, token_id: i64, data_ref: i64) -> bool {
         if to <= 0 || token_id <= 0 {
@@ -320,54 +784,146 @@ impl MultiTenantNftPlatformRust {
             return false;
         }
 
-        let collection_id = read_i64(&storage, &token_field_key(token_id, TOKEN_FIELD_COLLECTION_ID));
-        let token_class = read_i64(&storage, &token_field_key(token_id, TOKEN_FIELD_CLASS));
-        if read_bool(&storage, &collection_field_key(collection_id, FIELD_PAUSED)) {
+        let from = read_i64(&storage, &token_field_key(token_id, TOKEN_FIELD_OWNER));
+        if !check_witness_for_account_ref(&storage, from) {
             return false;
         }
 
-        if !read_bool(&storage, &collection_field_key(collection_id, FIELD_TRANSFERABLE)) {
+        let transfer_data = neo_devpack::abi::resolve_value(data_ref).unwrap_or(NeoValue::Null);
+        transfer_token(&storage, to_id, token_id, transfer_data)
+    }
+
+    #[neo_method(name = "transferFrom", param_types = ["Hash160", "Hash160", "ByteArray", "Any"])]
+    pub fn transfer_from(spender: i64, to: i64, token_id: i64, data_ref: i64) -> bool {
+        if spender <= 0 || to <= 0 || token_id <= 0 {
             return false;
         }
 
-        if token_class == TOKEN_CLASS_MEMBERSHIP && is_membership_soulbound(&storage, collection_id) {
+        let Some(storage) = storage_context() else {
+            return false;
+        };
+
+        let spender_id = canonical_account_id(&storage, spender);
+        let to_id = canonical_account_id(&storage, to);
+        if spender_id <= 0 || to_id <= 0 || !check_witness_for_account_ref(&storage, spender) {
             return false;
         }
 
-        let from = read_i64(&storage, &token_field_key(token_id, TOKEN_FIELD_OWNER));
-        if !check_witness_for_account_ref(&storage, from) {
+        if !token_exists(&storage, token_id) || read_bool(&storage, &token_field_key(token_id, TOKEN_FIELD_BURNED)) {
             return false;
         }
 
-        if from == to_id {
-            return true;
+        if !is_approved_spender(&storage, token_id, spender_id) {
+            return false;
         }
 
-        let from_balance = load_balance(&storage, from);
-        if from_balance > 0 && !save_balance(&storage, from, from_balance - 1) {
+        let transfer_data = neo_devpack::abi::resolve_value(data_ref).unwrap_or(NeoValue::Null);
+        transfer_token(&storage, to_id, token_id, transfer_data)
+    }
+
+    #[neo_method(name = "approve", param_types = ["Hash160", "ByteArray", "Any"])]
+    pub fn approve(approver: i64, token_id: i64, spender: i64) -> bool {
+        if approver <= 0 || token_id <= 0 {
             return false;
         }
 
-        let to_balance = load_balance(&storage, to_id);
-        if !save_balance(&storage, to_id, to_balance + 1)
-            || !write_i64(&storage, &token_field_key(token_id, TOKEN_FIELD_OWNER), to_id)
-        {
+        let Some(storage) = storage_context() else {
+            return false;
+        };
+
+        let approver_id = canonical_account_id(&storage, approver);
+        if approver_id <= 0 || !check_witness_for_account_ref(&storage, approver) {
             return false;
         }
 
-        if token_class == TOKEN_CLASS_MEMBERSHIP
-            && (!subtract_membership_balance(&storage, collection_id, from, 1)
-                || !add_membership_balance(&storage, collection_id, to_id, 1))
-        {
+        if !token_exists(&storage, token_id) || read_bool(&storage, &token_field_key(token_id, TOKEN_FIELD_BURNED)) {
             return false;
         }
 
-        let transfer_data = neo_devpack::abi::resolve_value(data_ref).unwrap_or(NeoValue::Null);
-        emit_token_upserted(&storage, token_id);
-        emit_transfer(&storage, Some(from), Some(to_id), token_id);
-        if !call_nep11_receiver(&storage, Some(from), to_id, token_id, transfer_data) {
-            panic!("Invalid NEP-11 receiver");
+        // The owner or one of their approved-for-all operators may set the single-token approval.
+        let owner = read_i64(&storage, &token_field_key(token_id, TOKEN_FIELD_OWNER));
+        if approver_id != owner && !read_bool(&storage, &approval_for_all_key(owner, approver_id)) {
+            return false;
+        }
+
+        // Null clears the current approval; anything else must resolve to an account.
+        let spender_id = match optional_account_id(&storage, spender) {
+            Some(account_id) => account_id,
+            None if spender <= 0 || matches!(neo_devpack::abi::resolve_value(spender), Some(NeoValue::Null)) => 0,
+            None => return false,
+        };
+        if spender_id == owner {
+            return false;
+        }
+
+        if !write_i64(&storage, &token_field_key(token_id, TOKEN_FIELD_APPROVED), spender_id) {
+            return false;
+        }
+
+        emit_approval(&storage, owner, spender_id, token_id);
+        true
+    }
+
+    #[neo_method(name = "getApproved", safe, param_types = ["ByteArray"], return_type = "Hash160")]
+    pub fn get_approved(token_id: i64) -> i64 {
+        if token_id <= 0 {
+            return 0;
+        }
+
+        let Some(storage) = storage_context() else {
+            return 0;
+        };
+
+        if !token_exists(&storage, token_id) {
+            return 0;
+        }
+
+        hash160_ref_from_account_id(
+            &storage,
+            read_i64(&storage, &token_field_key(token_id, TOKEN_FIELD_APPROVED)),
+        )
+    }
+
+    #[neo_method(name = "setApprovalForAll", param_types = ["Hash160", "Hash160", "Boolean"])]
+    pub fn set_approval_for_all(owner: i64, operator: i64, approved: bool) -> bool {
+        if owner <= 0 || operator <= 0 {
+            return false;
+        }
+
+        let Some(storage) = storage_context() else {
+            return false;
+        };
+
+        let owner_id = canonical_account_id(&storage, owner);
+        let operator_id = canonical_account_id(&storage, operator);
+        if owner_id <= 0 || operator_id <= 0 || owner_id == operator_id {
+            return false;
         }
+
+        if !check_witness_for_account_ref(&storage, owner) {
+            return false;
+        }
+
+        if !write_bool(&storage, &approval_for_all_key(owner_id, operator_id), approved) {
+            return false;
+        }
+
+        emit_approval_for_all(&storage, owner_id, operator_id, approved);
         true
     }
+
+    #[neo_method(name = "isApprovedForAll", safe, param_types = ["Hash160", "Hash160"])]
+    pub fn is_approved_for_all(owner: i64, operator: i64) -> bool {
+        if owner <= 0 || operator <= 0 {
+            return false;
+        }
+
+        let Some(storage) = storage_context() else {
+            return false;
+        };
+
+        let owner_id = canonical_account_id(&storage, owner);
+        let operator_id = canonical_account_id(&storage, operator);
+        owner_id > 0 && operator_id > 0 && read_bool(&storage, &approval_for_all_key(owner_id, operator_id))
+    }
 }
@@ -45,6 +45,75 @@ pub fn write_bytes(storage: &NeoStorageContext, key: &[u8], value: &NeoByteStrin
     NeoStorage::put(storage, &key_bs, value).is_ok()
 }
 
+// Iterator-backed index entries are the only keys that get deleted, since Storage.Find would otherwise yield stale rows.
+pub fn delete_key(storage: &NeoStorageContext, key: &[u8]) -> bool {
+    let key_bs = NeoByteString::from_slice(key);
+    NeoStorage::delete(storage, &key_bs).is_ok()
+}
+
+// Storage.Find with FindOptions.ValuesOnly, handed back to the VM as an InteropInterface iterator.
+pub fn find_values(storage: &NeoStorageContext, prefix: &[u8]) -> i64 {
+    const FIND_OPTIONS_VALUES_ONLY: i64 = 0x04;
+
+    let prefix_bs = NeoByteString::from_slice(prefix);
+    match NeoStorage::find(storage, &prefix_bs, FIND_OPTIONS_VALUES_ONLY) {
+        Ok(iterator) => neo_devpack::abi::i64_from_iterator(iterator),
+        Err(_) => 0,
+    }
+}
+
+// Walks an index whose keys end in a big-endian id, in ascending id order, until `visit` returns false.
+pub fn walk_find_ids<F: FnMut(i64) -> bool>(storage: &NeoStorageContext, prefix: &[u8], mut visit: F) {
+    const FIND_OPTIONS_KEYS_ONLY_REMOVE_PREFIX: i64 = 0x01 | 0x02;
+
+    let prefix_bs = NeoByteString::from_slice(prefix);
+    let Ok(mut iterator) = NeoStorage::find(storage, &prefix_bs, FIND_OPTIONS_KEYS_ONLY_REMOVE_PREFIX) else {
+        return;
+    };
+
+    while iterator.next() {
+        let NeoValue::ByteString(suffix) = iterator.value() else {
+            continue;
+        };
+        if suffix.len() != 8 {
+            continue;
+        }
+
+        let mut word = [0u8; 8];
+        word.copy_from_slice(suffix.as_slice());
+        if !visit(i64::from_be_bytes(word)) {
+            return;
+        }
+    }
+}
+
+// Ids strictly after `after`, so the last id of a page is a cursor that survives inserts and deletes.
+pub fn find_ids_after(storage: &NeoStorageContext, prefix: &[u8], after: i64, limit: i64) -> Vec<i64> {
+    let mut ids = Vec::new();
+    walk_find_ids(storage, prefix, |id| {
+        if id > after {
+            ids.push(id);
+        }
+        (ids.len() as i64) < limit
+    });
+    ids
+}
+
+// One-based position in id order; 0 when the index holds fewer entries.
+pub fn find_id_at(storage: &NeoStorageContext, prefix: &[u8], index: i64) -> i64 {
+    let mut position = 0;
+    let mut found = 0;
+    walk_find_ids(storage, prefix, |id| {
+        position += 1;
+        if position == index {
+            found = id;
+            return false;
+        }
+        true
+    });
+    found
+}
+
 pub fn now() -> i64 {
     NeoRuntime::get_time()
         .map(|v| v.as_i64_saturating())
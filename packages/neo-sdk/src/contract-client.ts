@@ -27,6 +27,9 @@ import type {
 import { NeoRpcService } from "./rpc.js";
 
 const HEX_CHARS = /^[0-9a-fA-F]+$/;
+const RUST_OPERATOR_PERMISSION_ALL = 0xfe;
+const RUST_MARKET_OP_BUY = 1;
+const GAS_CONTRACT_HASH = "0xd2a4cff31913016155e38e474a2c06d08be276cf";
 
 function utf8ToHex(input: string): string {
   const bytes = new TextEncoder().encode(input);
@@ -236,6 +239,19 @@ export class NeoNftPlatformClient {
     return value === true;
   }
 
+  async getCollectionOperators(collectionIdHex: string, offset = 0, limit = 50): Promise<unknown> {
+    if (this.dialect !== "rust") {
+      throw new Error(`getCollectionOperators is not available for ${this.dialect} dialect`);
+    }
+
+    const [value] = await this.rpc.invokeRead("getCollectionOperators", [
+      toByteArrayArg(collectionIdHex),
+      integerArg(offset),
+      integerArg(limit),
+    ]);
+    return value;
+  }
+
   async getToken(tokenIdHex: string): Promise<unknown> {
     const [value] =
       this.dialect === "rust"
@@ -327,9 +343,45 @@ export class NeoNftPlatformClient {
     return value?.toString() ?? "0";
   }
 
+  // Rust pages are keyed by the last token id of the previous page (empty for the first) and return [total, items].
+  async getTokensPage(afterTokenIdHex = "", limit = 50): Promise<unknown> {
+    if (this.dialect !== "rust") {
+      throw new Error(`tokensPaged is not available for ${this.dialect} dialect`);
+    }
+
+    const [value] = await this.rpc.invokeRead("tokensPaged", [toByteArrayArg(afterTokenIdHex), integerArg(limit)]);
+    return value;
+  }
+
+  async getTokensOfPage(owner: string, afterTokenIdHex = "", limit = 50): Promise<unknown> {
+    if (this.dialect !== "rust") {
+      throw new Error(`tokensOfPaged is not available for ${this.dialect} dialect`);
+    }
+
+    const [value] = await this.rpc.invokeRead("tokensOfPaged", [
+      hash160Arg(owner),
+      toByteArrayArg(afterTokenIdHex),
+      integerArg(limit),
+    ]);
+    return value;
+  }
+
+  async getCollectionTokensPage(collectionIdHex: string, afterTokenIdHex = "", limit = 50): Promise<unknown> {
+    if (this.dialect !== "rust") {
+      throw new Error(`getCollectionTokensPaged is not available for ${this.dialect} dialect`);
+    }
+
+    const [value] = await this.rpc.invokeRead("getCollectionTokensPaged", [
+      toByteArrayArg(collectionIdHex),
+      toByteArrayArg(afterTokenIdHex),
+      integerArg(limit),
+    ]);
+    return value;
+  }
+
   async isTokenListed(tokenIdHex: string): Promise<boolean> {
-    if (this.dialect !== "csharp") {
-      throw new Error("isTokenListed is only available for csharp dialect");
+    if (this.dialect === "solidity") {
+      throw new Error("isTokenListed is not available for solidity dialect");
     }
 
     const [value] = await this.rpc.invokeRead("isTokenListed", [toByteArrayArg(tokenIdHex)]);
@@ -337,8 +389,8 @@ export class NeoNftPlatformClient {
   }
 
   async getTokenSale(tokenIdHex: string): Promise<unknown> {
-    if (this.dialect !== "csharp") {
-      throw new Error("getTokenSale is only available for csharp dialect");
+    if (this.dialect === "solidity") {
+      throw new Error("getTokenSale is not available for solidity dialect");
     }
 
     const [value] = await this.rpc.invokeRead("getTokenSale", [toByteArrayArg(tokenIdHex)]);
@@ -560,7 +612,7 @@ export class NeoNftPlatformClient {
           integerArg(toIntegerLike(payload.creatorRef ?? 1, 1)),
           integerArg(toIntegerLike(payload.collectionId, 0)),
           integerArg(toIntegerLike(payload.operatorRef ?? payload.operator, 0)),
-          boolArg(payload.enabled),
+          integerArg(payload.enabled ? toIntegerLike(payload.permissions ?? RUST_OPERATOR_PERMISSION_ALL, 0) : 0),
         ],
       };
     }
@@ -588,20 +640,21 @@ export class NeoNftPlatformClient {
     }
 
     if (this.dialect === "rust") {
-      if (hasExplicitTokenClass) {
-        throw new Error("tokenClass override is only available for csharp dialect");
-      }
+      const rustArgs = [
+        integerArg(toIntegerLike(payload.operatorRef ?? 1, 1)),
+        integerArg(toIntegerLike(payload.collectionId, 0)),
+        integerArg(toIntegerLike(payload.toRef ?? payload.to, 0)),
+        integerArg(toIntegerLike(payload.tokenUriRef ?? payload.tokenUri, 0)),
+        integerArg(toIntegerLike(payload.propertiesRef ?? payload.propertiesJson, 0)),
+      ];
 
       return {
         scriptHash: this.config.contractHash,
-        operation: "mint",
-        args: [
-          integerArg(toIntegerLike(payload.operatorRef ?? 1, 1)),
-          integerArg(toIntegerLike(payload.collectionId, 0)),
-          integerArg(toIntegerLike(payload.toRef ?? payload.to, 0)),
-          integerArg(toIntegerLike(payload.tokenUriRef ?? payload.tokenUri, 0)),
-          integerArg(toIntegerLike(payload.propertiesRef ?? payload.propertiesJson, 0)),
-        ],
+        operation: tokenClassValue === null ? "mint" : tokenClassValue === 0 ? "mintStandard" : "mintWithClass",
+        args:
+          tokenClassValue === null || tokenClassValue === 0
+            ? rustArgs
+            : [...rustArgs, integerArg(tokenClassValue)],
       };
     }
 
@@ -645,7 +698,26 @@ export class NeoNftPlatformClient {
 
   buildBatchMintInvoke(payload: BatchMintRequest): WalletInvokeRequest {
     if (this.dialect === "rust") {
-      throw new Error("Batch mint is not explicitly supported via standard SDK for Rust yet");
+      const amount = toIntegerLike(payload.amount, 0);
+      if (amount <= 0) {
+        throw new Error("amount must be a positive integer");
+      }
+
+      const repeat = (value: string) =>
+        arrayArg(Array.from({ length: amount }, () => integerArg(toIntegerLike(value, 0))));
+
+      return {
+        scriptHash: this.config.contractHash,
+        operation: "mintBatch",
+        args: [
+          integerArg(toIntegerLike(payload.operatorRef ?? 1, 1)),
+          integerArg(toIntegerLike(payload.collectionId, 0)),
+          repeat(payload.toRef ?? payload.to),
+          repeat(payload.tokenUriRef ?? payload.tokenUri),
+          repeat(payload.propertiesRef ?? payload.propertiesJson),
+          integerArg(toIntegerLike(payload.tokenClass ?? 1, 1)),
+        ],
+      };
     }
 
     if (this.dialect === "solidity") {
@@ -697,8 +769,20 @@ export class NeoNftPlatformClient {
   }
 
   buildListTokenForSaleInvoke(payload: ListTokenForSaleRequest): WalletInvokeRequest {
+    if (this.dialect === "rust") {
+      return {
+        scriptHash: this.config.contractHash,
+        operation: "listTokenForSale",
+        args: [
+          integerArg(toIntegerLike(payload.sellerRef ?? 1, 1)),
+          integerArg(toIntegerLike(payload.tokenId, 0)),
+          integerArg(payload.price),
+        ],
+      };
+    }
+
     if (this.dialect !== "csharp") {
-      throw new Error("listTokenForSale is only available for csharp dialect");
+      throw new Error("listTokenForSale is not available for solidity dialect");
     }
 
     return {
@@ -709,10 +793,6 @@ export class NeoNftPlatformClient {
   }
 
   buildCancelTokenSaleInvoke(request: string | CancelTokenSaleRequest): WalletInvokeRequest {
-    if (this.dialect !== "csharp") {
-      throw new Error("cancelTokenSale is only available for csharp dialect");
-    }
-
     const payload: CancelTokenSaleRequest =
       typeof request === "string"
         ? {
@@ -720,6 +800,18 @@ export class NeoNftPlatformClient {
         }
         : request;
 
+    if (this.dialect === "rust") {
+      return {
+        scriptHash: this.config.contractHash,
+        operation: "cancelTokenSale",
+        args: [integerArg(toIntegerLike(payload.sellerRef ?? 1, 1)), integerArg(toIntegerLike(payload.tokenId, 0))],
+      };
+    }
+
+    if (this.dialect !== "csharp") {
+      throw new Error("cancelTokenSale is not available for solidity dialect");
+    }
+
     return {
       scriptHash: this.config.contractHash,
       operation: "cancelTokenSale",
@@ -728,10 +820,6 @@ export class NeoNftPlatformClient {
   }
 
   buildBuyTokenInvoke(request: string | BuyTokenRequest): WalletInvokeRequest {
-    if (this.dialect !== "csharp") {
-      throw new Error("buyToken is only available for csharp dialect");
-    }
-
     const payload: BuyTokenRequest =
       typeof request === "string"
         ? {
@@ -739,6 +827,28 @@ export class NeoNftPlatformClient {
         }
         : request;
 
+    // The Rust contract sells through onNEP17Payment, so the buyer sends the exact listing price in GAS.
+    if (this.dialect === "rust") {
+      if (!payload.buyer || payload.price === undefined) {
+        throw new Error("buyToken on rust dialect requires buyer and price");
+      }
+
+      return {
+        scriptHash: GAS_CONTRACT_HASH,
+        operation: "transfer",
+        args: [
+          hash160Arg(payload.buyer),
+          hash160Arg(this.config.contractHash),
+          integerArg(payload.price),
+          arrayArg([integerArg(RUST_MARKET_OP_BUY), integerArg(toIntegerLike(payload.tokenId, 0))]),
+        ],
+      };
+    }
+
+    if (this.dialect !== "csharp") {
+      throw new Error("buyToken is not available for solidity dialect");
+    }
+
     return {
       scriptHash: this.config.contractHash,
       operation: "buyToken",
@@ -746,6 +856,19 @@ export class NeoNftPlatformClient {
     };
   }
 
+  // Rust sale proceeds, royalties and refunds accrue to a balance the account pulls out with withdraw.
+  buildWithdrawMarketBalanceInvoke(accountRef: string): WalletInvokeRequest {
+    if (this.dialect !== "rust") {
+      throw new Error(`withdraw is not available for ${this.dialect} dialect`);
+    }
+
+    return {
+      scriptHash: this.config.contractHash,
+      operation: "withdraw",
+      args: [integerArg(toIntegerLike(accountRef, 0))],
+    };
+  }
+
   buildTransferInvoke(toAddress: string, tokenIdHex: string, request?: Partial<TransferRequest>): WalletInvokeRequest {
     if (this.dialect === "rust") {
       return {
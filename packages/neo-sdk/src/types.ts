@@ -37,6 +37,7 @@ export interface SetCollectionOperatorRequest {
   collectionId: ByteStringHex;
   operator: string;
   enabled: boolean;
+  permissions?: number | string;
   creatorRef?: string;
   operatorRef?: string;
 }
@@ -72,14 +73,18 @@ export interface TransferRequest {
 export interface ListTokenForSaleRequest {
   tokenId: ByteStringHex;
   price: number | string;
+  sellerRef?: string;
 }
 
 export interface CancelTokenSaleRequest {
   tokenId: ByteStringHex;
+  sellerRef?: string;
 }
 
 export interface BuyTokenRequest {
   tokenId: ByteStringHex;
+  buyer?: string;
+  price?: number | string;
 }
 
 export interface SetCollectionContractTemplateRequest {
@@ -117,6 +117,18 @@ const SHARED_METHOD_SPECS = [
     arity: { csharp: 2, solidity: 2, rust: 2 },
     returnTypes: { csharp: ["Boolean"], solidity: ["Boolean"], rust: ["Boolean"] },
   },
+  {
+    name: "getCollectionOperators",
+    safe: true,
+    arity: { rust: 3 },
+    returnTypes: { rust: ["Array"] },
+  },
+  {
+    name: "backfillCollectionOperators",
+    safe: false,
+    arity: { rust: 2 },
+    returnTypes: { rust: ["Integer"] },
+  },
   {
     name: "mint",
     safe: false,
@@ -299,6 +311,155 @@ const SHARED_METHOD_SPECS = [
     arity: { csharp: 4, solidity: 4, rust: 4 },
     returnTypes: { csharp: ["Void"], solidity: ["Void"], rust: ["Void"] },
   },
+  {
+    name: "tokensPaged",
+    safe: true,
+    arity: { rust: 2 },
+    returnTypes: { rust: ["Array"] },
+  },
+  {
+    name: "tokensOfPaged",
+    safe: true,
+    arity: { rust: 3 },
+    returnTypes: { rust: ["Array"] },
+  },
+  {
+    name: "getCollectionTokensPaged",
+    safe: true,
+    arity: { rust: 3 },
+    returnTypes: { rust: ["Array"] },
+  },
+  // The GAS marketplace exists in the C# and Rust dialects only.
+  {
+    name: "listTokenForSale",
+    safe: false,
+    arity: { csharp: 2, rust: 3 },
+    returnTypes: { csharp: ["Void"], rust: ["Boolean"] },
+    rustFirstParamTypes: ["Hash160"],
+  },
+  {
+    name: "cancelTokenSale",
+    safe: false,
+    arity: { csharp: 1, rust: 2 },
+    returnTypes: { csharp: ["Void"], rust: ["Boolean"] },
+    rustFirstParamTypes: ["Hash160"],
+  },
+  {
+    name: "isTokenListed",
+    safe: true,
+    arity: { csharp: 1, rust: 1 },
+    returnTypes: { csharp: ["Boolean"], rust: ["Boolean"] },
+  },
+  {
+    name: "getTokenSale",
+    safe: true,
+    arity: { csharp: 1, rust: 1 },
+    returnTypes: { csharp: ["Array"], rust: ["Array"] },
+  },
+  {
+    name: "onNEP17Payment",
+    safe: false,
+    arity: { csharp: 3, rust: 3 },
+    returnTypes: { csharp: ["Void"], rust: ["Void"] },
+  },
+  {
+    name: "withdraw",
+    safe: false,
+    arity: { rust: 1 },
+    returnTypes: { rust: ["Boolean"] },
+    rustFirstParamTypes: ["Hash160"],
+  },
+  {
+    name: "acceptTokenOffer",
+    safe: false,
+    arity: { rust: 3 },
+    returnTypes: { rust: ["Boolean"] },
+    rustFirstParamTypes: ["Hash160"],
+  },
+  {
+    name: "acceptCollectionBid",
+    safe: false,
+    arity: { rust: 3 },
+    returnTypes: { rust: ["Boolean"] },
+    rustFirstParamTypes: ["Hash160"],
+  },
+  {
+    name: "cancelTokenOffer",
+    safe: false,
+    arity: { rust: 2 },
+    returnTypes: { rust: ["Boolean"] },
+    rustFirstParamTypes: ["Hash160"],
+  },
+  {
+    name: "cancelCollectionBid",
+    safe: false,
+    arity: { rust: 2 },
+    returnTypes: { rust: ["Boolean"] },
+    rustFirstParamTypes: ["Hash160"],
+  },
+  {
+    name: "refundExpiredTokenOffer",
+    safe: false,
+    arity: { rust: 2 },
+    returnTypes: { rust: ["Boolean"] },
+  },
+  {
+    name: "refundExpiredCollectionBid",
+    safe: false,
+    arity: { rust: 2 },
+    returnTypes: { rust: ["Boolean"] },
+  },
+  {
+    name: "getTokenOffers",
+    safe: true,
+    arity: { rust: 1 },
+    returnTypes: { rust: ["InteropInterface"] },
+  },
+  {
+    name: "getCollectionBids",
+    safe: true,
+    arity: { rust: 1 },
+    returnTypes: { rust: ["InteropInterface"] },
+  },
+  {
+    name: "createAuction",
+    safe: false,
+    arity: { rust: 4 },
+    returnTypes: { rust: ["Boolean"] },
+    rustFirstParamTypes: ["Hash160"],
+  },
+  {
+    name: "cancelAuction",
+    safe: false,
+    arity: { rust: 2 },
+    returnTypes: { rust: ["Boolean"] },
+    rustFirstParamTypes: ["Hash160"],
+  },
+  {
+    name: "settleAuction",
+    safe: false,
+    arity: { rust: 1 },
+    returnTypes: { rust: ["Boolean"] },
+  },
+  {
+    name: "abandonAuction",
+    safe: false,
+    arity: { rust: 2 },
+    returnTypes: { rust: ["Boolean"] },
+    rustFirstParamTypes: ["Hash160"],
+  },
+  {
+    name: "getAuction",
+    safe: true,
+    arity: { rust: 1 },
+    returnTypes: { rust: ["Array"] },
+  },
+  {
+    name: "getMarketBalance",
+    safe: true,
+    arity: { rust: 1 },
+    returnTypes: { rust: ["Integer"] },
+  },
 ];
 
 function checkStandards(label, manifest, errors) {
@@ -392,7 +553,7 @@ function checkCsharpRoleSplitSource(errors) {
 
 function checkSharedMethods(methodMaps, errors) {
   for (const spec of SHARED_METHOD_SPECS) {
-    for (const dialect of ["csharp", "solidity", "rust"]) {
+    for (const dialect of ["csharp", "solidity", "rust"].filter((name) => name in spec.arity)) {
       const method = getMethodByArity(methodMaps[dialect], spec.name, spec.arity[dialect]);
       if (!method) {
         errors.push(